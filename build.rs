@@ -0,0 +1,354 @@
+//! Generates the [`symbols`](src/symbols.rs) constants, the codepoint table and the list of directional symbols
+//! from the vendored data files, and, when one of the `svg-*` features is enabled, the inline SVG path data.
+//! With the `embed-font` feature, it also encodes the embedded font as a data URI,
+//! and with the `metadata` feature, it generates the icon metadata table.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+const CODEPOINTS: &str = "data/codepoints";
+/// Symbols that point in the reading direction and are mirrored in right-to-left layouts
+const DIRECTIONAL: &str = "data/directional";
+
+fn main() {
+    println!("cargo:rerun-if-changed={CODEPOINTS}");
+    println!("cargo:rerun-if-changed={DIRECTIONAL}");
+
+    let codepoints = fs::read_to_string(CODEPOINTS).expect("failed to read codepoints file");
+    let codepoints: Vec<(&str, &str)> = codepoints
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|line| {
+            line.split_once(' ')
+                .unwrap_or_else(|| panic!("malformed line in codepoints file: `{line}`"))
+        })
+        .collect();
+
+    let mut symbols = String::new();
+    for (name, codepoint) in &codepoints {
+        writeln!(
+            symbols,
+            "/// `{name}` (U+{codepoint})\npub const {}: &Symbol = &Symbol(\"{name}\");",
+            const_name(name),
+            codepoint = codepoint.to_uppercase(),
+        )
+        .unwrap();
+    }
+    write_out("symbols.rs", &symbols);
+
+    let mut sorted = codepoints.clone();
+    sorted.sort_unstable_by_key(|(name, _)| *name);
+    let mut table = String::from("&[\n");
+    for (name, codepoint) in sorted {
+        writeln!(table, "    (\"{name}\", '\\u{{{codepoint}}}'),").unwrap();
+    }
+    table.push(']');
+    write_out("codepoints.rs", &table);
+
+    let directional = fs::read_to_string(DIRECTIONAL).expect("failed to read directional file");
+    let mut directional: Vec<&str> = directional
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .collect();
+    directional.sort_unstable();
+    let mut table = String::from("&[\n");
+    for name in directional {
+        assert!(
+            codepoints.iter().any(|(n, _)| *n == name),
+            "unknown symbol `{name}` in directional file"
+        );
+        writeln!(table, "    {},", const_name(name)).unwrap();
+    }
+    table.push(']');
+    write_out("directional.rs", &table);
+
+    #[cfg(feature = "svg-outlined")]
+    svg::generate("outlined", &codepoints);
+    #[cfg(feature = "svg-rounded")]
+    svg::generate("rounded", &codepoints);
+    #[cfg(feature = "svg-sharp")]
+    svg::generate("sharp", &codepoints);
+
+    #[cfg(feature = "embed-font")]
+    embedded::generate();
+
+    #[cfg(feature = "metadata")]
+    metadata::generate(&codepoints);
+}
+
+fn write_out(file: &str, contents: &str) {
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join(file);
+    fs::write(out, contents).unwrap_or_else(|e| panic!("failed to write generated {file}: {e}"));
+}
+
+/// Converts a symbol name to its constant name, e.g. `arrow_back` to `ARROW_BACK`
+///
+/// Names starting with a digit are prefixed with an underscore, e.g. `3d_rotation` becomes `_3D_ROTATION`.
+fn const_name(name: &str) -> String {
+    let upper = name.to_uppercase();
+    if upper.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{upper}")
+    } else {
+        upper
+    }
+}
+
+#[cfg(any(
+    feature = "svg-outlined",
+    feature = "svg-rounded",
+    feature = "svg-sharp"
+))]
+mod svg {
+    //! Extracts the glyph outlines of a font as SVG path data in a `0 0 24 24` view box.
+
+    use std::env;
+    use std::fmt::Write;
+    use std::fs;
+
+    use ttf_parser::gsub::SubstitutionSubtable;
+    use ttf_parser::{Face, GlyphId, OutlineBuilder};
+
+    /// Extracts the path data of a style from its Material Symbols font
+    ///
+    /// The font is `data/fonts/MaterialSymbols<Style>.ttf`, unless overridden with `DIOXUS_MATERIAL_SYMBOLS_SVG_FONT_<STYLE>`.
+    /// Each style needs its own font, as the shapes differ between the styles.
+    /// Glyphs are found by the ligatures of the font rather than by codepoint, so they match the names
+    /// even where the codepoints of the font differ from the bundled ones.
+    pub fn generate(style: &str, codepoints: &[(&str, &str)]) {
+        let var = format!("DIOXUS_MATERIAL_SYMBOLS_SVG_FONT_{}", style.to_uppercase());
+        println!("cargo:rerun-if-env-changed={var}");
+        let title = style[..1].to_uppercase() + &style[1..];
+        let font =
+            env::var(&var).unwrap_or_else(|_| format!("data/fonts/MaterialSymbols{title}.ttf"));
+        println!("cargo:rerun-if-changed={font}");
+
+        let data = fs::read(&font).unwrap_or_else(|e| {
+            panic!(
+                "failed to read font `{font}` for the `svg-{style}` feature: {e}\n\
+                 Add the Material Symbols {title} font, e.g. from \
+                 https://github.com/google/material-design-icons/tree/master/variablefont (the default instance is used), \
+                 or set `{var}` to its absolute path"
+            )
+        });
+        let face =
+            Face::parse(&data, 0).unwrap_or_else(|e| panic!("failed to parse `{font}`: {e}"));
+
+        let mut table = String::from("&[\n");
+        let mut sorted = codepoints.to_vec();
+        sorted.sort_unstable_by_key(|(name, _)| *name);
+        for (name, _) in sorted {
+            let Some(path) = ligature(&face, name).and_then(|glyph| {
+                let mut path = PathBuilder::new(&face);
+                face.outline_glyph(glyph, &mut path)?;
+                Some(path.d)
+            }) else {
+                continue;
+            };
+            writeln!(table, "    (\"{name}\", \"{}\"),", path.trim_end()).unwrap();
+        }
+        table.push(']');
+        super::write_out(&format!("svg_{style}.rs"), &table);
+    }
+
+    /// Finds the glyph the font substitutes for the ligature `name`
+    fn ligature(face: &Face, name: &str) -> Option<GlyphId> {
+        let glyphs: Vec<GlyphId> = name
+            .chars()
+            .map(|c| face.glyph_index(c))
+            .collect::<Option<_>>()?;
+        let (first, rest) = glyphs.split_first()?;
+        for lookup in face.tables().gsub?.lookups {
+            for subtable in lookup.subtables.into_iter::<SubstitutionSubtable>() {
+                let SubstitutionSubtable::Ligature(ligatures) = subtable else {
+                    continue;
+                };
+                let Some(set) = ligatures
+                    .coverage
+                    .get(*first)
+                    .and_then(|i| ligatures.ligature_sets.get(i))
+                else {
+                    continue;
+                };
+                if let Some(ligature) = set
+                    .into_iter()
+                    .find(|l| l.components.into_iter().eq(rest.iter().copied()))
+                {
+                    return Some(ligature.glyph);
+                }
+            }
+        }
+        None
+    }
+
+    /// Writes the outline as SVG path commands, flipping the y axis and scaling to 24 units
+    struct PathBuilder {
+        d: String,
+        scale: f32,
+        ascender: f32,
+    }
+
+    impl PathBuilder {
+        fn new(face: &Face) -> Self {
+            Self {
+                d: String::new(),
+                scale: 24.0 / f32::from(face.units_per_em()),
+                ascender: f32::from(face.ascender()),
+            }
+        }
+
+        fn point(&mut self, x: f32, y: f32) {
+            let x = x * self.scale;
+            let y = (self.ascender - y) * self.scale;
+            write!(self.d, "{} {} ", round(x), round(y)).unwrap();
+        }
+    }
+
+    fn round(v: f32) -> f32 {
+        (v * 100.0).round() / 100.0
+    }
+
+    impl OutlineBuilder for PathBuilder {
+        fn move_to(&mut self, x: f32, y: f32) {
+            self.d.push('M');
+            self.point(x, y);
+        }
+
+        fn line_to(&mut self, x: f32, y: f32) {
+            self.d.push('L');
+            self.point(x, y);
+        }
+
+        fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+            self.d.push('Q');
+            self.point(x1, y1);
+            self.point(x, y);
+        }
+
+        fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+            self.d.push('C');
+            self.point(x1, y1);
+            self.point(x2, y2);
+            self.point(x, y);
+        }
+
+        fn close(&mut self) {
+            self.d.push_str("Z ");
+        }
+    }
+}
+
+#[cfg(feature = "embed-font")]
+mod embedded {
+    //! Embeds the font file as bytes and as a base64 data URI for the `@font-face` rule.
+
+    use std::env;
+    use std::fs;
+    use std::path::Path;
+
+    use base64::Engine;
+
+    /// Font embedded unless overridden with `DIOXUS_MATERIAL_SYMBOLS_EMBEDDED_FONT`
+    const DEFAULT_FONT: &str = "data/fonts/MaterialIcons-Regular.ttf";
+
+    pub fn generate() {
+        const VAR: &str = "DIOXUS_MATERIAL_SYMBOLS_EMBEDDED_FONT";
+        println!("cargo:rerun-if-env-changed={VAR}");
+        let font = env::var(VAR).unwrap_or_else(|_| DEFAULT_FONT.to_string());
+        println!("cargo:rerun-if-changed={font}");
+        let path = fs::canonicalize(&font)
+            .unwrap_or_else(|e| panic!("failed to find embedded font `{font}`: {e}"));
+        let data = fs::read(&path).unwrap_or_else(|e| panic!("failed to read `{font}`: {e}"));
+
+        let (mime, format) = match Path::new(&font).extension().and_then(|e| e.to_str()) {
+            Some("woff2") => ("font/woff2", "woff2"),
+            Some("woff") => ("font/woff", "woff"),
+            Some("otf") => ("font/otf", "opentype"),
+            _ => ("font/ttf", "truetype"),
+        };
+        let src = format!(
+            "url(\"data:{mime};base64,{}\") format('{format}')",
+            base64::engine::general_purpose::STANDARD.encode(data)
+        );
+
+        super::write_out(
+            "embedded.rs",
+            &format!(
+                "/// Bytes of the embedded font file\n\
+                 pub static EMBEDDED_FONT: &[u8] = include_bytes!({path:?});\n\
+                 pub(crate) const EMBEDDED_FONT_SRC: &str = {src:?};\n"
+            ),
+        );
+    }
+}
+
+#[cfg(feature = "metadata")]
+mod metadata {
+    //! Combines the codepoints with the categories, tags and popularity of the Google Fonts metadata.
+
+    use std::collections::HashMap;
+    use std::env;
+    use std::fmt::Write;
+    use std::fs;
+
+    use serde_json::Value;
+
+    /// Metadata used unless overridden with `DIOXUS_MATERIAL_SYMBOLS_METADATA`
+    ///
+    /// The file is the response of <https://fonts.google.com/metadata/icons>, and the build fails without it.
+    /// Symbols it doesn't contain have no categories, tags or popularity.
+    const DEFAULT_METADATA: &str = "data/metadata.json";
+
+    pub fn generate(codepoints: &[(&str, &str)]) {
+        const VAR: &str = "DIOXUS_MATERIAL_SYMBOLS_METADATA";
+        println!("cargo:rerun-if-env-changed={VAR}");
+        let file = env::var(VAR).unwrap_or_else(|_| DEFAULT_METADATA.to_string());
+        println!("cargo:rerun-if-changed={file}");
+
+        let json = fs::read_to_string(&file).unwrap_or_else(|e| {
+            panic!(
+                "failed to read metadata `{file}` for the `metadata` feature: {e}\n\
+                 Download https://fonts.google.com/metadata/icons to `{DEFAULT_METADATA}`, \
+                 or set `{VAR}` to the absolute path of a copy"
+            )
+        });
+        // The response starts with `)]}'` to prevent it from being executed as a script
+        let json = json.trim_start_matches(")]}'");
+        let metadata: Value = serde_json::from_str(json)
+            .unwrap_or_else(|e| panic!("failed to parse metadata `{file}`: {e}"));
+        let icons: HashMap<&str, &Value> = metadata["icons"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|icon| Some((icon["name"].as_str()?, icon)))
+            .collect();
+
+        let mut table = String::from("&[\n");
+        let mut sorted = codepoints.to_vec();
+        sorted.sort_unstable_by_key(|(name, _)| *name);
+        for (name, codepoint) in sorted {
+            let icon = icons.get(name).copied().unwrap_or(&Value::Null);
+            writeln!(
+                table,
+                "    IconMetadata {{ name: {name:?}, codepoint: '\\u{{{codepoint}}}', categories: &{:?}, tags: &{:?}, popularity: {} }},",
+                strings(&icon["categories"]),
+                strings(&icon["tags"]),
+                icon["popularity"].as_u64().unwrap_or(0),
+            )
+            .unwrap();
+        }
+        table.push(']');
+        super::write_out("metadata.rs", &table);
+    }
+
+    fn strings(value: &Value) -> Vec<&str> {
+        value
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .collect()
+    }
+}
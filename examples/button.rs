@@ -3,35 +3,52 @@
 //! # Button Example
 //!
 //! This example renders a material icon into a button which can be clicked to toggle the
-//! color of the icon.
+//! color of the icon, followed by a Material 3 toggle button.
+//!
+//! Run it with `cargo run --example button --features desktop`.
 
 use dioxus::prelude::*;
 
-use dioxus_material_symbols::{MaterialIcon, MaterialIconStylesheet, MaterialIconVariant};
+use dioxus_material_symbols::{
+    MaterialIcon, MaterialIconButton, MaterialIconButtonKind, MaterialIconStylesheet,
+    MaterialIconVariant, SelfHostedFont,
+};
 
 fn main() {
-    dioxus_desktop::launch(App);
+    dioxus::launch(App);
 }
 
-fn App(cx: Scope) -> Element {
-    let is_blue = use_state(&cx, || false);
+fn App() -> Element {
+    let mut is_blue = use_signal(|| false);
+    let mut is_favorite = use_signal(|| false);
 
-    cx.render(rsx!(
+    rsx!(
         MaterialIconStylesheet {
             // Uses the self-hosted approach
-            variant: MaterialIconVariant::SelfHosted("examples/assets/MaterialIcons-Regular.ttf")
+            variant: MaterialIconVariant::SelfHosted(SelfHostedFont {
+                sources: vec!["data/fonts/MaterialIcons-Regular.ttf".into()],
+                ..Default::default()
+            })
         }
         button {
             style: "padding: 10; font-size: 48px;",
-            onclick: move |_| is_blue.set(!is_blue),
+            onclick: move |_| is_blue.toggle(),
             // The size prop was omitted, so both icons inherit their size from the button element above
-            if *is_blue.get() {
+            if is_blue() {
                 // Render material icon "home" in blue
-                rsx!(MaterialIcon { name: "home", color: "blue" })
+                MaterialIcon { name: "home", color: "blue" }
             } else {
                 // Render material icon "home" in default color
-                rsx!(MaterialIcon { name: "home" })
+                MaterialIcon { name: "home" }
             }
         }
-    ))
+        // Renders a Material 3 toggle button, which shows the filled symbol when selected
+        MaterialIconButton {
+            name: "favorite",
+            label: "Favorite",
+            kind: MaterialIconButtonKind::Tonal,
+            selected: is_favorite(),
+            onclick: move |_| is_favorite.toggle(),
+        }
+    )
 }
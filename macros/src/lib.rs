@@ -0,0 +1,109 @@
+#![warn(missing_docs)]
+
+//! # Dioxus Material Symbols Macros
+//!
+//! Procedural macros for [`dioxus-material-symbols`](https://crates.io/crates/dioxus-material-symbols).
+//! Use them through the re-exports in that crate.
+
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, LitStr};
+
+/// Codepoints file of the main crate, symlinked into this crate
+const CODEPOINTS: &str = include_str!("../codepoints");
+
+/// Fewer symbols than this means the codepoints file is not the real one
+///
+/// Checkouts without symlink support replace the symlink with a text file containing its target.
+const MIN_SYMBOLS: usize = 1000;
+
+/// Checks a symbol name at compile time
+///
+/// Expands to the matching constant in `dioxus_material_symbols::symbols`,
+/// or fails to compile with a suggestion if the symbol does not exist.
+///
+/// ```ignore
+/// MaterialIcon { name: icon!("arrow_back") }
+/// ```
+#[proc_macro]
+pub fn icon(input: TokenStream) -> TokenStream {
+    let lit = parse_macro_input!(input as LitStr);
+    let name = lit.value();
+
+    if names().count() < MIN_SYMBOLS {
+        let message = "the codepoints file of dioxus-material-symbols-macros is missing or incomplete, \
+            check that `macros/codepoints` is a symlink to `data/codepoints` (e.g. with `git config core.symlinks true`)";
+        return syn::Error::new(lit.span(), message)
+            .to_compile_error()
+            .into();
+    }
+    if names().any(|n| n == name) {
+        let ident = format_ident!("{}", const_name(&name));
+        return quote!(::dioxus_material_symbols::symbols::#ident).into();
+    }
+
+    let message = match suggestion(&name) {
+        Some(suggestion) => format!("unknown symbol `{name}`, did you mean `{suggestion}`?"),
+        None => format!("unknown symbol `{name}`"),
+    };
+    syn::Error::new(lit.span(), message)
+        .to_compile_error()
+        .into()
+}
+
+/// Returns the names of all symbols in the codepoints file
+fn names() -> impl Iterator<Item = &'static str> {
+    CODEPOINTS
+        .lines()
+        .filter_map(|line| line.split_whitespace().next())
+}
+
+/// Finds the closest symbol name, if any is close enough to be a likely typo
+fn suggestion(name: &str) -> Option<&'static str> {
+    let max_distance = (name.len() / 3).max(1);
+    names()
+        .map(|candidate| (strsim::levenshtein(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Converts a symbol name to its constant name, see the build script of the main crate
+fn const_name(name: &str) -> String {
+    let upper = name.to_uppercase();
+    if upper.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{upper}")
+    } else {
+        upper
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codepoints_file_is_complete() {
+        assert!(names().count() >= MIN_SYMBOLS);
+        assert!(names().any(|name| name == "home"));
+    }
+
+    #[test]
+    fn suggests_close_names() {
+        assert_eq!(suggestion("arow_back"), Some("arrow_back"));
+        assert_eq!(suggestion("setings"), Some("settings"));
+        assert_eq!(suggestion("hme"), Some("home"));
+    }
+
+    #[test]
+    fn does_not_suggest_distant_names() {
+        assert_eq!(suggestion("definitely_not_a_symbol"), None);
+        assert_eq!(suggestion("x"), None);
+    }
+
+    #[test]
+    fn converts_const_names() {
+        assert_eq!(const_name("arrow_back"), "ARROW_BACK");
+        assert_eq!(const_name("3d_rotation"), "_3D_ROTATION");
+    }
+}
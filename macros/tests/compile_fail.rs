@@ -0,0 +1,6 @@
+//! Checks the compile errors of the macros
+
+#[test]
+fn compile_fail() {
+    trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,5 @@
+use dioxus_material_symbols_macros::icon;
+
+fn main() {
+    let _ = icon!("definitely_not_a_symbol");
+}
@@ -0,0 +1,140 @@
+//! Built-in icon animations
+
+use std::time::Duration;
+
+/// Keyframes of all animations, included by the [`MaterialIconStylesheet`](crate::MaterialIconStylesheet)
+pub(crate) const CSS: &str = include_str!("./animation-styles.css");
+
+/// Kinds of built-in animations
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum MaterialIconAnimationKind {
+    /// Rotates the icon clockwise, e.g. for `progress_activity` or `sync`
+    #[default]
+    Spin,
+    /// Fades the icon out and in, e.g. for alerts
+    Pulse,
+    /// Bounces the icon up and down
+    Bounce,
+    /// Shakes the icon from side to side, e.g. for errors
+    Shake,
+}
+
+impl MaterialIconAnimationKind {
+    /// Returns the class of the animation, which is also the name of its keyframes
+    fn class(&self) -> &'static str {
+        match self {
+            MaterialIconAnimationKind::Spin => "material-symbols-spin",
+            MaterialIconAnimationKind::Pulse => "material-symbols-pulse",
+            MaterialIconAnimationKind::Bounce => "material-symbols-bounce",
+            MaterialIconAnimationKind::Shake => "material-symbols-shake",
+        }
+    }
+}
+
+/// Animation of an icon
+///
+/// The keyframes are defined once by the [`MaterialIconStylesheet`](crate::MaterialIconStylesheet).
+/// Animations are disabled when the user prefers reduced motion.
+///
+/// Spinning and pulsing repeat by default, bouncing and shaking play once.
+/// Animations that play once start when they are set, so they can be replayed by removing and setting them again.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use dioxus_material_symbols::MaterialIconAnimation;
+/// let loading = MaterialIconAnimation::spin();
+/// let alert = MaterialIconAnimation::pulse().duration(Duration::from_millis(800)).iterations(3);
+/// ```
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct MaterialIconAnimation {
+    /// Kind of the animation
+    pub kind: MaterialIconAnimationKind,
+    /// Duration of a single iteration
+    pub duration: Duration,
+    /// Number of iterations, or `None` to repeat forever
+    pub iterations: Option<u32>,
+}
+
+impl Default for MaterialIconAnimation {
+    fn default() -> Self {
+        MaterialIconAnimationKind::default().into()
+    }
+}
+
+impl From<MaterialIconAnimationKind> for MaterialIconAnimation {
+    fn from(kind: MaterialIconAnimationKind) -> Self {
+        let (duration, iterations) = match kind {
+            MaterialIconAnimationKind::Spin => (1000, None),
+            MaterialIconAnimationKind::Pulse => (2000, None),
+            MaterialIconAnimationKind::Bounce => (1000, Some(1)),
+            MaterialIconAnimationKind::Shake => (500, Some(1)),
+        };
+        Self {
+            kind,
+            duration: Duration::from_millis(duration),
+            iterations,
+        }
+    }
+}
+
+impl MaterialIconAnimation {
+    /// Spins forever, once per second
+    pub fn spin() -> Self {
+        MaterialIconAnimationKind::Spin.into()
+    }
+
+    /// Pulses forever, once every two seconds
+    pub fn pulse() -> Self {
+        MaterialIconAnimationKind::Pulse.into()
+    }
+
+    /// Bounces once for a second
+    pub fn bounce() -> Self {
+        MaterialIconAnimationKind::Bounce.into()
+    }
+
+    /// Shakes once for half a second
+    pub fn shake() -> Self {
+        MaterialIconAnimationKind::Shake.into()
+    }
+
+    /// Sets the duration of a single iteration
+    pub fn duration(self, duration: Duration) -> Self {
+        Self { duration, ..self }
+    }
+
+    /// Sets the number of iterations
+    pub fn iterations(self, iterations: u32) -> Self {
+        Self {
+            iterations: Some(iterations),
+            ..self
+        }
+    }
+
+    /// Repeats the animation forever
+    pub fn infinite(self) -> Self {
+        Self {
+            iterations: None,
+            ..self
+        }
+    }
+
+    /// Returns the classes of the animation
+    pub(crate) fn class(&self) -> String {
+        format!("material-symbols-animated {}", self.kind.class())
+    }
+
+    /// Returns the inline styles of the animation
+    ///
+    /// The name of the keyframes is set by the class, so the stylesheet can disable it under reduced motion.
+    pub(crate) fn style(&self) -> String {
+        let iterations = self
+            .iterations
+            .map(|i| i.to_string())
+            .unwrap_or_else(|| "infinite".to_string());
+        format!(
+            "animation-duration: {}ms; animation-iteration-count: {iterations};",
+            self.duration.as_millis()
+        )
+    }
+}
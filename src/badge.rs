@@ -0,0 +1,105 @@
+//! Material 3 badges on icons
+
+use dioxus::prelude::*;
+
+/// Styles of all badges, included by the [`MaterialIconStylesheet`](crate::MaterialIconStylesheet)
+pub(crate) const CSS: &str = include_str!("./badge-styles.css");
+
+/// [Material 3 badge](https://m3.material.io/components/badges/overview) on an icon
+///
+/// The badge is anchored to the top end of the icon at any size, i.e. the top left in right-to-left layouts.
+/// A count of zero hides the badge.
+///
+/// ```
+/// # use dioxus_material_symbols::MaterialIconBadge;
+/// let dot = MaterialIconBadge::dot().label("New messages");
+/// let count = MaterialIconBadge::count(1500).max(999);
+/// ```
+#[derive(PartialEq, Clone, Debug)]
+pub struct MaterialIconBadge {
+    /// Count shown in the badge, or a small dot if `None`
+    pub count: Option<u32>,
+    /// Largest count that is shown, larger ones are truncated (e.g. `999+`)
+    pub max: u32,
+    /// Description of the badge for assistive technology (e.g. `3 unread messages`)
+    ///
+    /// Defaults to the untruncated count. A dot without a label is not announced.
+    pub label: Option<String>,
+}
+
+impl Default for MaterialIconBadge {
+    fn default() -> Self {
+        Self {
+            count: None,
+            max: 999,
+            label: None,
+        }
+    }
+}
+
+impl MaterialIconBadge {
+    /// Small badge without a count
+    pub fn dot() -> Self {
+        Self::default()
+    }
+
+    /// Large badge with a count
+    pub fn count(count: u32) -> Self {
+        Self {
+            count: Some(count),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the largest count that is shown
+    pub fn max(self, max: u32) -> Self {
+        Self { max, ..self }
+    }
+
+    /// Sets the description of the badge for assistive technology
+    pub fn label(self, label: impl Into<String>) -> Self {
+        Self {
+            label: Some(label.into()),
+            ..self
+        }
+    }
+
+    /// Returns the visible text, e.g. `999+`
+    fn text(&self) -> Option<String> {
+        self.count.map(|count| {
+            if count > self.max {
+                format!("{}+", self.max)
+            } else {
+                count.to_string()
+            }
+        })
+    }
+
+    /// Renders the icon with the badge anchored to it
+    pub(crate) fn render(&self, icon: Element) -> Element {
+        if self.count == Some(0) {
+            return icon;
+        }
+        let label = self
+            .label
+            .clone()
+            .or_else(|| self.count.map(|count| count.to_string()));
+        let class = match self.count {
+            Some(_) => "material-symbols-badge",
+            None => "material-symbols-badge material-symbols-badge-dot",
+        };
+        rsx!(
+            span { class: "material-symbols-badged",
+                {icon}
+                span { class,
+                    if let Some(text) = self.text() {
+                        span { aria_hidden: "true", "{text}" }
+                    }
+                    if let Some(label) = label {
+                        span { class: "material-symbols-visually-hidden", "{label}" }
+                    }
+                }
+            }
+        )
+    }
+}
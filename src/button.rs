@@ -0,0 +1,150 @@
+//! Material 3 icon buttons
+
+use dioxus::prelude::*;
+
+use crate::{CssColor, MaterialIcon, MaterialIconMode, MaterialIconSize, MaterialIconStyle};
+
+/// Styles of all icon buttons, included by the [`MaterialIconStylesheet`](crate::MaterialIconStylesheet)
+pub(crate) const CSS: &str = include_str!("./button-styles.css");
+
+/// Props for the [`MaterialIconButton`](MaterialIconButton) component
+#[derive(Props, Clone, PartialEq)]
+pub struct MaterialIconButtonProps {
+    /// Name of the symbol (e.g. `home` or [`symbols::HOME`](crate::symbols::HOME))
+    #[props(into)]
+    pub name: String,
+    /// Accessible label (e.g. `Open settings`)
+    ///
+    /// Icon buttons have no visible text, so the label is required.
+    #[props(into)]
+    pub label: String,
+    /// Kind of the button
+    ///
+    /// See [`MaterialIconButtonKind`](MaterialIconButtonKind) for more information.
+    #[props(default)]
+    pub kind: MaterialIconButtonKind,
+    /// Whether a toggle button is selected
+    ///
+    /// Makes the button a toggle button, which shows the filled symbol when selected.
+    /// Update it from [`onclick`](Self::onclick) to toggle the button.
+    ///
+    /// Optional
+    pub selected: Option<bool>,
+    /// Disables the button
+    #[props(default)]
+    pub disabled: bool,
+    /// Tooltip shown on hover
+    ///
+    /// Optional
+    #[props(into)]
+    pub title: Option<String>,
+    /// Click handler
+    ///
+    /// Also called when the button is activated with the keyboard.
+    ///
+    /// Optional
+    pub onclick: Option<EventHandler<MouseEvent>>,
+    /// Style of the symbol
+    ///
+    /// See [`MaterialIconProps::variant`](crate::MaterialIconProps::variant).
+    ///
+    /// Optional
+    pub variant: Option<MaterialIconStyle>,
+    /// Rendering mode of the symbol
+    ///
+    /// See [`MaterialIconMode`](MaterialIconMode) for more information.
+    #[props(default)]
+    pub mode: MaterialIconMode,
+    /// Additional CSS classes
+    ///
+    /// Optional
+    #[props(into)]
+    pub class: Option<String>,
+    /// Any other attributes, passed through to the `button` element
+    #[props(extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+}
+
+/// Kinds of [Material 3 icon buttons](https://m3.material.io/components/icon-buttons/overview)
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum MaterialIconButtonKind {
+    /// No container, for the most common actions
+    #[default]
+    Standard,
+    /// Filled container in the primary color, for the most important actions
+    Filled,
+    /// Filled container in the secondary color
+    Tonal,
+    /// Outlined container
+    Outlined,
+}
+
+impl MaterialIconButtonKind {
+    /// Returns the CSS class of this kind
+    fn class(&self) -> &'static str {
+        match self {
+            MaterialIconButtonKind::Standard => "material-symbols-button-standard",
+            MaterialIconButtonKind::Filled => "material-symbols-button-filled",
+            MaterialIconButtonKind::Tonal => "material-symbols-button-tonal",
+            MaterialIconButtonKind::Outlined => "material-symbols-button-outlined",
+        }
+    }
+}
+
+/// Material Icon Button component
+///
+/// Renders a symbol inside a native `button` with the Material 3 states for hover, focus, pressed and disabled.
+/// Being a native button, it can be focused and activated with Enter and Space.
+/// Its styles are included by the [`MaterialIconStylesheet`](crate::MaterialIconStylesheet).
+///
+/// ```
+/// # use dioxus::prelude::*;
+/// # use dioxus_material_symbols::{MaterialIconButton, MaterialIconButtonKind};
+/// #[component]
+/// fn Favorite() -> Element {
+///     let mut favorite = use_signal(|| false);
+///     rsx!(
+///         MaterialIconButton {
+///             name: "favorite",
+///             label: "Favorite",
+///             kind: MaterialIconButtonKind::Tonal,
+///             selected: favorite(),
+///             onclick: move |_| favorite.toggle(),
+///         }
+///     )
+/// }
+/// ```
+#[component]
+pub fn MaterialIconButton(props: MaterialIconButtonProps) -> Element {
+    let class = match &props.class {
+        Some(extra) => format!("material-symbols-button {} {extra}", props.kind.class()),
+        None => format!("material-symbols-button {}", props.kind.class()),
+    };
+    let aria_pressed = props.selected.map(|selected| selected.to_string());
+    let onclick = move |event| {
+        if let Some(handler) = &props.onclick {
+            handler.call(event);
+        }
+    };
+    rsx!(
+        button {
+            r#type: "button",
+            class,
+            aria_label: props.label,
+            aria_pressed,
+            title: props.title,
+            disabled: props.disabled,
+            onclick,
+            ..props.attributes,
+            MaterialIcon {
+                name: props.name,
+                size: MaterialIconSize::DP_24,
+                // The color depends on the state of the button, not on the icon theme
+                color: CssColor::CurrentColor,
+                fill: props.selected,
+                variant: props.variant,
+                mode: props.mode,
+            }
+        }
+    )
+}
@@ -0,0 +1,705 @@
+//! Material 3 color roles and palettes, and parsed CSS colors
+
+use std::fmt::{self, Write};
+use std::str::FromStr;
+
+macro_rules! color_roles {
+    ($($(#[$doc:meta])* $role:ident => $token:literal, $fallback:literal, $palette:ident($light:literal, $dark:literal);)*) => {
+        /// [Material 3 color roles](https://m3.material.io/styles/color/roles)
+        ///
+        /// Roles resolve to the CSS custom properties of the Material 3 color tokens (e.g. `--md-sys-color-primary`),
+        /// so icons follow the theme of the app. When a property is not defined, the color of the
+        /// baseline light scheme is used as fallback.
+        #[derive(PartialEq, Clone, Copy, Debug)]
+        pub enum ColorRole {
+            $($(#[$doc])* $role,)*
+        }
+
+        impl ColorRole {
+            /// All color roles
+            pub const ALL: &'static [ColorRole] = &[$(ColorRole::$role),*];
+
+            /// Returns the name of the CSS custom property of this role, e.g. `--md-sys-color-primary`
+            pub fn custom_property(&self) -> &'static str {
+                match self {
+                    $(ColorRole::$role => concat!("--md-sys-color-", $token),)*
+                }
+            }
+
+            /// Returns the CSS color of this role, e.g. `var(--md-sys-color-primary, #6750a4)`
+            pub fn to_css_color(&self) -> &'static str {
+                match self {
+                    $(ColorRole::$role => concat!("var(--md-sys-color-", $token, ", ", $fallback, ")"),)*
+                }
+            }
+
+            /// Returns the tonal palette and the tones in the light and dark scheme
+            fn tone(&self) -> (Palette, u8, u8) {
+                match self {
+                    $(ColorRole::$role => (Palette::$palette, $light, $dark),)*
+                }
+            }
+        }
+    };
+}
+
+color_roles! {
+    /// High-emphasis elements, e.g. the most important actions
+    Primary => "primary", "#6750a4", Primary(40, 80);
+    /// Content on [`Primary`](Self::Primary)
+    OnPrimary => "on-primary", "#ffffff", Primary(100, 20);
+    /// Less prominent containers of primary elements
+    PrimaryContainer => "primary-container", "#eaddff", Primary(90, 30);
+    /// Content on [`PrimaryContainer`](Self::PrimaryContainer)
+    OnPrimaryContainer => "on-primary-container", "#21005d", Primary(10, 90);
+    /// Less prominent elements, e.g. filter chips
+    Secondary => "secondary", "#625b71", Secondary(40, 80);
+    /// Content on [`Secondary`](Self::Secondary)
+    OnSecondary => "on-secondary", "#ffffff", Secondary(100, 20);
+    /// Less prominent containers of secondary elements, e.g. tonal buttons
+    SecondaryContainer => "secondary-container", "#e8def8", Secondary(90, 30);
+    /// Content on [`SecondaryContainer`](Self::SecondaryContainer)
+    OnSecondaryContainer => "on-secondary-container", "#1d192b", Secondary(10, 90);
+    /// Contrasting accents
+    Tertiary => "tertiary", "#7d5260", Tertiary(40, 80);
+    /// Content on [`Tertiary`](Self::Tertiary)
+    OnTertiary => "on-tertiary", "#ffffff", Tertiary(100, 20);
+    /// Containers of contrasting accents
+    TertiaryContainer => "tertiary-container", "#ffd8e4", Tertiary(90, 30);
+    /// Content on [`TertiaryContainer`](Self::TertiaryContainer)
+    OnTertiaryContainer => "on-tertiary-container", "#31111d", Tertiary(10, 90);
+    /// Errors, e.g. invalid input
+    Error => "error", "#b3261e", Error(40, 80);
+    /// Content on [`Error`](Self::Error)
+    OnError => "on-error", "#ffffff", Error(100, 20);
+    /// Containers of errors
+    ErrorContainer => "error-container", "#f9dedc", Error(90, 30);
+    /// Content on [`ErrorContainer`](Self::ErrorContainer)
+    OnErrorContainer => "on-error-container", "#410e0b", Error(10, 90);
+    /// Default background
+    Surface => "surface", "#fef7ff", Neutral(98, 6);
+    /// Content on surfaces, e.g. most icons
+    OnSurface => "on-surface", "#1d1b20", Neutral(10, 90);
+    /// Alternative background
+    SurfaceVariant => "surface-variant", "#e7e0ec", NeutralVariant(90, 30);
+    /// Less prominent content on surfaces, e.g. standard icon buttons
+    OnSurfaceVariant => "on-surface-variant", "#49454f", NeutralVariant(30, 80);
+    /// Container with the lowest emphasis
+    SurfaceContainerLowest => "surface-container-lowest", "#ffffff", Neutral(100, 4);
+    /// Container with low emphasis
+    SurfaceContainerLow => "surface-container-low", "#f7f2fa", Neutral(96, 10);
+    /// Container with default emphasis
+    SurfaceContainer => "surface-container", "#f3edf7", Neutral(94, 12);
+    /// Container with high emphasis
+    SurfaceContainerHigh => "surface-container-high", "#ece6f0", Neutral(92, 17);
+    /// Container with the highest emphasis
+    SurfaceContainerHighest => "surface-container-highest", "#e6e0e9", Neutral(90, 22);
+    /// Borders of important elements
+    Outline => "outline", "#79747e", NeutralVariant(50, 60);
+    /// Decorative borders, e.g. dividers
+    OutlineVariant => "outline-variant", "#cac4d0", NeutralVariant(80, 30);
+    /// Background of elements that contrast with the surface, e.g. snackbars
+    InverseSurface => "inverse-surface", "#322f35", Neutral(20, 90);
+    /// Content on [`InverseSurface`](Self::InverseSurface)
+    InverseOnSurface => "inverse-on-surface", "#f5eff7", Neutral(95, 20);
+    /// Primary elements on [`InverseSurface`](Self::InverseSurface)
+    InversePrimary => "inverse-primary", "#d0bcff", Primary(80, 40);
+}
+
+/// Tonal palettes a color scheme is derived from
+#[derive(Clone, Copy)]
+enum Palette {
+    Primary,
+    Secondary,
+    Tertiary,
+    Error,
+    Neutral,
+    NeutralVariant,
+}
+
+impl Palette {
+    /// Returns the hue and saturation of the palette for a seed color
+    fn hue_saturation(&self, seed_hue: f32, seed_saturation: f32) -> (f32, f32) {
+        match self {
+            Palette::Primary => (seed_hue, seed_saturation.max(0.36)),
+            Palette::Secondary => (seed_hue, seed_saturation.min(0.16)),
+            Palette::Tertiary => ((seed_hue + 60.0) % 360.0, seed_saturation.clamp(0.24, 0.4)),
+            Palette::Error => (3.0, 0.71),
+            Palette::Neutral => (seed_hue, seed_saturation.min(0.06)),
+            Palette::NeutralVariant => (seed_hue, seed_saturation.min(0.12)),
+        }
+    }
+}
+
+/// Parsed and validated CSS color
+///
+/// Parsed from hex colors (`#rgb`, `#rgba`, `#rrggbb` and `#rrggbbaa`), `rgb()`, `rgba()`, `hsl()` and `hsla()`
+/// in the comma and space separated syntax, the [named colors](https://developer.mozilla.org/en-US/docs/Web/CSS/named-color),
+/// `transparent` and `currentColor`. The color is always written back in a canonical form,
+/// so it is safe to put into a `style` attribute.
+///
+/// ```
+/// # use dioxus_material_symbols::CssColor;
+/// let color: CssColor = "rebeccapurple".parse().unwrap();
+/// assert_eq!(color.to_string(), "#663399");
+/// assert_eq!(color.opacity(0.5).to_string(), "rgba(102, 51, 153, 0.5)");
+/// assert_eq!("hsl(0 100% 50%)".parse::<CssColor>().unwrap().to_string(), "#ff0000");
+/// assert!("red; background: url(x)".parse::<CssColor>().is_err());
+/// ```
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum CssColor {
+    /// sRGB color with an alpha channel from 0 to 1
+    Rgba {
+        /// Red channel
+        red: u8,
+        /// Green channel
+        green: u8,
+        /// Blue channel
+        blue: u8,
+        /// Opacity, from 0 (transparent) to 1 (opaque)
+        alpha: f32,
+    },
+    /// `currentColor`, the text color of the element
+    CurrentColor,
+}
+
+/// Error returned when a string is not a valid [`CssColor`](CssColor)
+#[derive(PartialEq, Clone, Debug)]
+pub struct ParseColorError(String);
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid CSS color `{}`", self.0)
+    }
+}
+
+impl std::error::Error for ParseColorError {}
+
+impl CssColor {
+    /// Opaque color from its channels
+    pub fn rgb(red: u8, green: u8, blue: u8) -> Self {
+        Self::rgba(red, green, blue, 1.0)
+    }
+
+    /// Color from its channels and opacity, which is clamped to 0 to 1
+    pub fn rgba(red: u8, green: u8, blue: u8, alpha: f32) -> Self {
+        CssColor::Rgba {
+            red,
+            green,
+            blue,
+            alpha: clamp_unit(alpha),
+        }
+    }
+
+    /// Multiplies the opacity of the color, e.g. `0.38` for disabled icons
+    ///
+    /// `currentColor` is returned unchanged.
+    pub fn opacity(self, opacity: f32) -> Self {
+        match self {
+            CssColor::Rgba {
+                red,
+                green,
+                blue,
+                alpha,
+            } => Self::rgba(red, green, blue, alpha * clamp_unit(opacity)),
+            CssColor::CurrentColor => self,
+        }
+    }
+
+    /// Increases the HSL lightness of the color by `amount`, from 0 to 1
+    ///
+    /// `currentColor` is returned unchanged.
+    pub fn lighten(self, amount: f32) -> Self {
+        match self {
+            CssColor::Rgba {
+                red,
+                green,
+                blue,
+                alpha,
+            } => {
+                let (hue, saturation, lightness) = rgb_to_hsl([red, green, blue]);
+                let [red, green, blue] =
+                    hsl_to_rgb(hue, saturation, clamp_unit(lightness + amount));
+                Self::rgba(red, green, blue, alpha)
+            }
+            CssColor::CurrentColor => self,
+        }
+    }
+
+    /// Decreases the HSL lightness of the color by `amount`, from 0 to 1
+    ///
+    /// `currentColor` is returned unchanged.
+    pub fn darken(self, amount: f32) -> Self {
+        self.lighten(-amount)
+    }
+}
+
+impl fmt::Display for CssColor {
+    /// Writes the color as `#rrggbb` if it is opaque, otherwise as `rgba()`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            CssColor::Rgba {
+                red,
+                green,
+                blue,
+                alpha,
+            } if alpha >= 1.0 => write!(f, "#{red:02x}{green:02x}{blue:02x}"),
+            CssColor::Rgba {
+                red,
+                green,
+                blue,
+                alpha,
+            } => {
+                let alpha = (alpha * 1000.0).round() / 1000.0;
+                write!(f, "rgba({red}, {green}, {blue}, {alpha})")
+            }
+            CssColor::CurrentColor => f.write_str("currentColor"),
+        }
+    }
+}
+
+impl FromStr for CssColor {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.trim().to_ascii_lowercase();
+        let color = if let Some(hex) = value.strip_prefix('#') {
+            parse_hex(hex).map(|[r, g, b, a]| Self::rgba(r, g, b, f32::from(a) / 255.0))
+        } else if let Some((function, arguments)) =
+            value.strip_suffix(')').and_then(|v| v.split_once('('))
+        {
+            match function.trim_end() {
+                "rgb" | "rgba" => parse_rgb(arguments),
+                "hsl" | "hsla" => parse_hsl(arguments),
+                _ => None,
+            }
+        } else {
+            match value.as_str() {
+                "currentcolor" => Some(CssColor::CurrentColor),
+                "transparent" => Some(Self::rgba(0, 0, 0, 0.0)),
+                name => NAMED_COLORS
+                    .binary_search_by_key(&name, |(name, _)| name)
+                    .ok()
+                    .map(|i| {
+                        let [_, r, g, b] = NAMED_COLORS[i].1.to_be_bytes();
+                        Self::rgb(r, g, b)
+                    }),
+            }
+        };
+        color.ok_or_else(|| ParseColorError(s.to_string()))
+    }
+}
+
+/// Splits the arguments of a color function into three channels and an optional alpha
+///
+/// Supports the legacy comma separated syntax (`1, 2, 3, 0.5`) and the space separated one (`1 2 3 / 0.5`).
+fn split_arguments(arguments: &str) -> Option<([&str; 3], Option<&str>)> {
+    let (channels, alpha): (Vec<&str>, _) = if arguments.contains(',') {
+        let mut parts: Vec<&str> = arguments.split(',').map(str::trim).collect();
+        let alpha = (parts.len() == 4).then(|| parts.pop()).flatten();
+        (parts, alpha)
+    } else {
+        let (channels, alpha) = match arguments.split_once('/') {
+            Some((channels, alpha)) => (channels, Some(alpha.trim())),
+            None => (arguments, None),
+        };
+        (channels.split_whitespace().collect(), alpha)
+    };
+    let channels: [&str; 3] = channels.try_into().ok()?;
+    Some((channels, alpha))
+}
+
+/// Parses the arguments of `rgb()` and `rgba()`
+fn parse_rgb(arguments: &str) -> Option<CssColor> {
+    let (channels, alpha) = split_arguments(arguments)?;
+    let channel = |value: &str| {
+        let value = match value.strip_suffix('%') {
+            Some(percentage) => number(percentage)? * 2.55,
+            None => number(value)?,
+        };
+        Some(value.round().clamp(0.0, 255.0) as u8)
+    };
+    Some(CssColor::rgba(
+        channel(channels[0])?,
+        channel(channels[1])?,
+        channel(channels[2])?,
+        parse_alpha(alpha)?,
+    ))
+}
+
+/// Parses the arguments of `hsl()` and `hsla()`
+fn parse_hsl(arguments: &str) -> Option<CssColor> {
+    let (channels, alpha) = split_arguments(arguments)?;
+    let hue = match channels[0] {
+        hue if hue.ends_with("deg") => number(hue.strip_suffix("deg")?)?,
+        hue if hue.ends_with("turn") => number(hue.strip_suffix("turn")?)? * 360.0,
+        hue => number(hue)?,
+    };
+    let percentage = |value: &str| {
+        Some(clamp_unit(
+            number(value.strip_suffix('%').unwrap_or(value))? / 100.0,
+        ))
+    };
+    let [red, green, blue] = hsl_to_rgb(
+        hue.rem_euclid(360.0),
+        percentage(channels[1])?,
+        percentage(channels[2])?,
+    );
+    Some(CssColor::rgba(red, green, blue, parse_alpha(alpha)?))
+}
+
+/// Parses an optional alpha value, which is a number from 0 to 1 or a percentage
+fn parse_alpha(alpha: Option<&str>) -> Option<f32> {
+    match alpha {
+        Some(alpha) => match alpha.strip_suffix('%') {
+            Some(percentage) => Some(number(percentage)? / 100.0),
+            None => number(alpha),
+        },
+        None => Some(1.0),
+    }
+}
+
+/// Parses a finite number
+fn number(value: &str) -> Option<f32> {
+    value.trim().parse::<f32>().ok().filter(|n| n.is_finite())
+}
+
+/// Clamps a value to the range from 0 to 1
+fn clamp_unit(value: f32) -> f32 {
+    if value.is_nan() {
+        0.0
+    } else {
+        value.clamp(0.0, 1.0)
+    }
+}
+
+/// Generates the CSS custom properties of all [color roles](ColorRole) from a seed color
+///
+/// The seed has to be a hex color (`#rrggbb` or `#rgb`), otherwise `None` is returned.
+/// The palettes are an approximation of the Material 3 tonal palettes in the HSL color space.
+/// For the exact colors, export a theme from the [Material Theme Builder](https://material-foundation.github.io/material-theme-builder/) instead.
+///
+/// ```
+/// # use dioxus_material_symbols::color_palette;
+/// let palette = color_palette("#6750a4", false).unwrap();
+/// let css = format!(":root {{ {palette} }}");
+/// assert!(css.contains("--md-sys-color-primary: #"));
+/// ```
+pub fn color_palette(seed: &str, dark: bool) -> Option<String> {
+    let [r, g, b, _] = parse_hex(seed.trim().strip_prefix('#')?)?;
+    let (hue, saturation, _) = rgb_to_hsl([r, g, b]);
+    let mut css = String::new();
+    for role in ColorRole::ALL {
+        let (palette, light_tone, dark_tone) = role.tone();
+        let tone = if dark { dark_tone } else { light_tone };
+        let (hue, saturation) = palette.hue_saturation(hue, saturation);
+        let [r, g, b] = hsl_to_rgb(hue, saturation, f32::from(tone) / 100.0);
+        write!(css, "{}: #{r:02x}{g:02x}{b:02x}; ", role.custom_property()).unwrap();
+    }
+    Some(css.trim_end().to_string())
+}
+
+/// Parses the digits of a `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa` hex color
+fn parse_hex(hex: &str) -> Option<[u8; 4]> {
+    // `from_str_radix` accepts a leading sign, e.g. in `#+f+f+f`
+    if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    let len = match hex.len() {
+        3 | 4 => 1,
+        6 | 8 => 2,
+        _ => return None,
+    };
+    let channel = |i: usize| {
+        let value = u8::from_str_radix(hex.get(i * len..(i + 1) * len)?, 16).ok()?;
+        Some(if len == 1 { value * 17 } else { value })
+    };
+    let alpha = match hex.len() / len {
+        4 => channel(3)?,
+        _ => 255,
+    };
+    Some([channel(0)?, channel(1)?, channel(2)?, alpha])
+}
+
+fn rgb_to_hsl([r, g, b]: [u8; 3]) -> (f32, f32, f32) {
+    let [r, g, b] = [r, g, b].map(|c| f32::from(c) / 255.0);
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let lightness = (max + min) / 2.0;
+    let delta = max - min;
+    if delta == 0.0 {
+        return (0.0, 0.0, lightness);
+    }
+    let saturation = delta / (1.0 - (2.0 * lightness - 1.0).abs());
+    let hue = if max == r {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+    (hue, saturation, lightness)
+}
+
+fn hsl_to_rgb(hue: f32, saturation: f32, lightness: f32) -> [u8; 3] {
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+    let x = chroma * (1.0 - ((hue / 60.0).rem_euclid(2.0) - 1.0).abs());
+    let (r, g, b) = match hue {
+        h if h < 60.0 => (chroma, x, 0.0),
+        h if h < 120.0 => (x, chroma, 0.0),
+        h if h < 180.0 => (0.0, chroma, x),
+        h if h < 240.0 => (0.0, x, chroma),
+        h if h < 300.0 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = lightness - chroma / 2.0;
+    [r, g, b].map(|c| ((c + m) * 255.0).round().clamp(0.0, 255.0) as u8)
+}
+
+/// [Named colors](https://developer.mozilla.org/en-US/docs/Web/CSS/named-color), sorted by name
+const NAMED_COLORS: &[(&str, u32)] = &[
+    ("aliceblue", 0xf0f8ff),
+    ("antiquewhite", 0xfaebd7),
+    ("aqua", 0x00ffff),
+    ("aquamarine", 0x7fffd4),
+    ("azure", 0xf0ffff),
+    ("beige", 0xf5f5dc),
+    ("bisque", 0xffe4c4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xffebcd),
+    ("blue", 0x0000ff),
+    ("blueviolet", 0x8a2be2),
+    ("brown", 0xa52a2a),
+    ("burlywood", 0xdeb887),
+    ("cadetblue", 0x5f9ea0),
+    ("chartreuse", 0x7fff00),
+    ("chocolate", 0xd2691e),
+    ("coral", 0xff7f50),
+    ("cornflowerblue", 0x6495ed),
+    ("cornsilk", 0xfff8dc),
+    ("crimson", 0xdc143c),
+    ("cyan", 0x00ffff),
+    ("darkblue", 0x00008b),
+    ("darkcyan", 0x008b8b),
+    ("darkgoldenrod", 0xb8860b),
+    ("darkgray", 0xa9a9a9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xa9a9a9),
+    ("darkkhaki", 0xbdb76b),
+    ("darkmagenta", 0x8b008b),
+    ("darkolivegreen", 0x556b2f),
+    ("darkorange", 0xff8c00),
+    ("darkorchid", 0x9932cc),
+    ("darkred", 0x8b0000),
+    ("darksalmon", 0xe9967a),
+    ("darkseagreen", 0x8fbc8f),
+    ("darkslateblue", 0x483d8b),
+    ("darkslategray", 0x2f4f4f),
+    ("darkslategrey", 0x2f4f4f),
+    ("darkturquoise", 0x00ced1),
+    ("darkviolet", 0x9400d3),
+    ("deeppink", 0xff1493),
+    ("deepskyblue", 0x00bfff),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1e90ff),
+    ("firebrick", 0xb22222),
+    ("floralwhite", 0xfffaf0),
+    ("forestgreen", 0x228b22),
+    ("fuchsia", 0xff00ff),
+    ("gainsboro", 0xdcdcdc),
+    ("ghostwhite", 0xf8f8ff),
+    ("gold", 0xffd700),
+    ("goldenrod", 0xdaa520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xadff2f),
+    ("grey", 0x808080),
+    ("honeydew", 0xf0fff0),
+    ("hotpink", 0xff69b4),
+    ("indianred", 0xcd5c5c),
+    ("indigo", 0x4b0082),
+    ("ivory", 0xfffff0),
+    ("khaki", 0xf0e68c),
+    ("lavender", 0xe6e6fa),
+    ("lavenderblush", 0xfff0f5),
+    ("lawngreen", 0x7cfc00),
+    ("lemonchiffon", 0xfffacd),
+    ("lightblue", 0xadd8e6),
+    ("lightcoral", 0xf08080),
+    ("lightcyan", 0xe0ffff),
+    ("lightgoldenrodyellow", 0xfafad2),
+    ("lightgray", 0xd3d3d3),
+    ("lightgreen", 0x90ee90),
+    ("lightgrey", 0xd3d3d3),
+    ("lightpink", 0xffb6c1),
+    ("lightsalmon", 0xffa07a),
+    ("lightseagreen", 0x20b2aa),
+    ("lightskyblue", 0x87cefa),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xb0c4de),
+    ("lightyellow", 0xffffe0),
+    ("lime", 0x00ff00),
+    ("limegreen", 0x32cd32),
+    ("linen", 0xfaf0e6),
+    ("magenta", 0xff00ff),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66cdaa),
+    ("mediumblue", 0x0000cd),
+    ("mediumorchid", 0xba55d3),
+    ("mediumpurple", 0x9370db),
+    ("mediumseagreen", 0x3cb371),
+    ("mediumslateblue", 0x7b68ee),
+    ("mediumspringgreen", 0x00fa9a),
+    ("mediumturquoise", 0x48d1cc),
+    ("mediumvioletred", 0xc71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xf5fffa),
+    ("mistyrose", 0xffe4e1),
+    ("moccasin", 0xffe4b5),
+    ("navajowhite", 0xffdead),
+    ("navy", 0x000080),
+    ("oldlace", 0xfdf5e6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6b8e23),
+    ("orange", 0xffa500),
+    ("orangered", 0xff4500),
+    ("orchid", 0xda70d6),
+    ("palegoldenrod", 0xeee8aa),
+    ("palegreen", 0x98fb98),
+    ("paleturquoise", 0xafeeee),
+    ("palevioletred", 0xdb7093),
+    ("papayawhip", 0xffefd5),
+    ("peachpuff", 0xffdab9),
+    ("peru", 0xcd853f),
+    ("pink", 0xffc0cb),
+    ("plum", 0xdda0dd),
+    ("powderblue", 0xb0e0e6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xff0000),
+    ("rosybrown", 0xbc8f8f),
+    ("royalblue", 0x4169e1),
+    ("saddlebrown", 0x8b4513),
+    ("salmon", 0xfa8072),
+    ("sandybrown", 0xf4a460),
+    ("seagreen", 0x2e8b57),
+    ("seashell", 0xfff5ee),
+    ("sienna", 0xa0522d),
+    ("silver", 0xc0c0c0),
+    ("skyblue", 0x87ceeb),
+    ("slateblue", 0x6a5acd),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xfffafa),
+    ("springgreen", 0x00ff7f),
+    ("steelblue", 0x4682b4),
+    ("tan", 0xd2b48c),
+    ("teal", 0x008080),
+    ("thistle", 0xd8bfd8),
+    ("tomato", 0xff6347),
+    ("turquoise", 0x40e0d0),
+    ("violet", 0xee82ee),
+    ("wheat", 0xf5deb3),
+    ("white", 0xffffff),
+    ("whitesmoke", 0xf5f5f5),
+    ("yellow", 0xffff00),
+    ("yellowgreen", 0x9acd32),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(value: &str) -> Option<String> {
+        value
+            .parse::<CssColor>()
+            .ok()
+            .map(|color| color.to_string())
+    }
+
+    #[test]
+    fn hex_colors() {
+        assert_eq!(parse("#F0A").as_deref(), Some("#ff00aa"));
+        assert_eq!(parse("#f0a8").as_deref(), Some("rgba(255, 0, 170, 0.533)"));
+        assert_eq!(parse("#6750a4").as_deref(), Some("#6750a4"));
+        assert_eq!(
+            parse("#6750a480").as_deref(),
+            Some("rgba(103, 80, 164, 0.502)")
+        );
+        assert_eq!(parse("#6750a4ff").as_deref(), Some("#6750a4"));
+        for invalid in ["#+f+f+f", "#-1-1-1", "#ggg", "#12345", "#", "#ff ff ff"] {
+            assert_eq!(parse(invalid), None, "{invalid}");
+        }
+    }
+
+    #[test]
+    fn rgb_functions() {
+        assert_eq!(parse("rgb(255, 0, 170)").as_deref(), Some("#ff00aa"));
+        assert_eq!(parse("rgb(100% 0% 50%)").as_deref(), Some("#ff0080"));
+        assert_eq!(
+            parse("rgba(255, 0, 170, 0.5)").as_deref(),
+            Some("rgba(255, 0, 170, 0.5)")
+        );
+        assert_eq!(
+            parse("rgb(255 0 170 / 25%)").as_deref(),
+            Some("rgba(255, 0, 170, 0.25)")
+        );
+        // Out of range channels and alpha are clamped
+        assert_eq!(parse("rgb(300, -5, 170, 2)").as_deref(), Some("#ff00aa"));
+    }
+
+    #[test]
+    fn hsl_functions() {
+        assert_eq!(parse("hsl(120, 100%, 50%)").as_deref(), Some("#00ff00"));
+        assert_eq!(parse("hsl(120deg 100% 50%)").as_deref(), Some("#00ff00"));
+        assert_eq!(parse("hsl(0.5turn 100% 50%)").as_deref(), Some("#00ffff"));
+        assert_eq!(parse("hsl(-240 100% 50%)").as_deref(), Some("#00ff00"));
+        assert_eq!(
+            parse("hsla(0, 100%, 50%, 50%)").as_deref(),
+            Some("rgba(255, 0, 0, 0.5)")
+        );
+    }
+
+    #[test]
+    fn wrong_argument_counts() {
+        for invalid in [
+            "rgb()",
+            "rgb(1, 2)",
+            "rgb(1, 2, 3, 4, 5)",
+            "rgb(1 2)",
+            "rgb(1 2 3 4)",
+            "rgb(1 2 3 / )",
+            "hsl(1, 2%)",
+            "hsl(1 2% 3% 4%)",
+        ] {
+            assert_eq!(parse(invalid), None, "{invalid}");
+        }
+    }
+
+    #[test]
+    fn non_finite_numbers() {
+        for invalid in [
+            "rgb(nan, 0, 0)",
+            "rgb(inf 0 0)",
+            "rgb(0, 0, 0, nan)",
+            "rgb(infinity% 0% 0%)",
+            "hsl(nan 100% 50%)",
+            "hsl(infdeg 100% 50%)",
+            "hsl(0 nan% 50%)",
+        ] {
+            assert_eq!(parse(invalid), None, "{invalid}");
+        }
+    }
+
+    #[test]
+    fn other_colors() {
+        assert_eq!(parse(" RebeccaPurple ").as_deref(), Some("#663399"));
+        assert_eq!(parse("transparent").as_deref(), Some("rgba(0, 0, 0, 0)"));
+        assert_eq!(parse("currentcolor").as_deref(), Some("currentColor"));
+        for invalid in ["notacolor", "rgb(1, 2, 3); color: red", "url(x)", ""] {
+            assert_eq!(parse(invalid), None, "{invalid}");
+        }
+    }
+}
@@ -0,0 +1,153 @@
+//! `@font-face` rules for self-hosted and embedded fonts
+
+use std::path::Path;
+
+use crate::MaterialIconStyle;
+
+/// Configuration of a self-hosted font file
+///
+/// ```
+/// # use dioxus_material_symbols::{FontAxes, MaterialIconStyle, SelfHostedFont};
+/// let font = SelfHostedFont {
+///     style: MaterialIconStyle::Rounded,
+///     sources: vec![
+///         "/fonts/MaterialSymbolsRounded.woff2".into(),
+///         "/fonts/MaterialSymbolsRounded.ttf".into(),
+///     ],
+///     axes: FontAxes::MATERIAL_SYMBOLS,
+///     ..Default::default()
+/// };
+/// ```
+#[derive(PartialEq, Clone, Debug)]
+pub struct SelfHostedFont {
+    /// Style the font file represents
+    ///
+    /// Determines the font family and the CSS class the font is used for.
+    pub style: MaterialIconStyle,
+    /// Urls of the font file in different formats, in order of preference
+    ///
+    /// The `format()` hint is detected from the file extension (`.woff2`, `.woff`, `.ttf` or `.otf`).
+    /// You can download the files [here](https://github.com/google/material-design-icons/tree/master/variablefont).
+    pub sources: Vec<String>,
+    /// How the font is displayed while it is loading
+    pub display: FontDisplay,
+    /// Variable font axes supported by the font file
+    pub axes: FontAxes,
+}
+
+impl Default for SelfHostedFont {
+    fn default() -> Self {
+        Self {
+            style: MaterialIconStyle::Outlined,
+            sources: Vec::new(),
+            display: FontDisplay::default(),
+            axes: FontAxes::default(),
+        }
+    }
+}
+
+impl SelfHostedFont {
+    /// Returns the `src` descriptor of the `@font-face` rule
+    pub(crate) fn src(&self) -> String {
+        self.sources
+            .iter()
+            .map(|source| match format_hint(source) {
+                Some(format) => format!("{} format('{format}')", url(source)),
+                None => url(source),
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// Returns the quoted `url()` of a font source
+fn url(source: &str) -> String {
+    let escaped = source.replace('\\', "\\\\").replace('"', "\\\"");
+    format!("url(\"{escaped}\")")
+}
+
+/// Returns the `format()` hint for the extension of a font url
+fn format_hint(url: &str) -> Option<&'static str> {
+    let path = url.split(['?', '#']).next().unwrap_or(url);
+    let extension = Path::new(path).extension()?.to_str()?;
+    match extension.to_ascii_lowercase().as_str() {
+        "woff2" => Some("woff2"),
+        "woff" => Some("woff"),
+        "ttf" => Some("truetype"),
+        "otf" => Some("opentype"),
+        _ => None,
+    }
+}
+
+/// Values of the [`font-display`](https://developer.mozilla.org/en-US/docs/Web/CSS/@font-face/font-display) descriptor
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum FontDisplay {
+    /// Lets the browser decide
+    Auto,
+    /// Hides the text for a short period, then swaps in the font whenever it loads
+    ///
+    /// Recommended for icon fonts, as the fallback text is the symbol name.
+    #[default]
+    Block,
+    /// Shows the fallback text immediately, then swaps in the font whenever it loads
+    Swap,
+    /// Hides the text for a very short period, then swaps in the font only if it loads soon
+    Fallback,
+    /// Hides the text for a very short period, then only uses the font if it is already available
+    Optional,
+}
+
+impl FontDisplay {
+    /// Converts the value to its CSS keyword
+    pub fn to_css(&self) -> &'static str {
+        match self {
+            FontDisplay::Auto => "auto",
+            FontDisplay::Block => "block",
+            FontDisplay::Swap => "swap",
+            FontDisplay::Fallback => "fallback",
+            FontDisplay::Optional => "optional",
+        }
+    }
+}
+
+/// Ranges of the variable font axes declared by the `@font-face` rule
+///
+/// `None` means the font does not have the axis. The default has none, i.e. a static font.
+/// Only the weight needs to be declared, the browser clamps the other axes to the ranges of the font file itself.
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub struct FontAxes {
+    /// Range of the `wght` axis
+    pub weight: Option<(u16, u16)>,
+}
+
+impl FontAxes {
+    /// Axes of the Material Symbols variable fonts
+    pub const MATERIAL_SYMBOLS: FontAxes = FontAxes {
+        weight: Some((100, 700)),
+    };
+
+    /// Returns the value of the `font-weight` descriptor, which is a range for variable fonts
+    fn font_weight(&self) -> String {
+        match self.weight {
+            Some((min, max)) => format!("{min} {max}"),
+            None => "normal".to_string(),
+        }
+    }
+}
+
+/// Renders the `@font-face` rule and the style class for a font
+pub(crate) fn css(
+    style: MaterialIconStyle,
+    src: &str,
+    display: FontDisplay,
+    axes: FontAxes,
+) -> String {
+    format!(
+        include_str!("./self-hosted-styles.css"),
+        family = style.family(),
+        class = style.class(),
+        weight = axes.font_weight(),
+        display = display.to_css(),
+        src = src,
+    )
+}
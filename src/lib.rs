@@ -7,21 +7,92 @@
 
 use dioxus::prelude::*;
 
+mod animation;
+mod badge;
+mod button;
+mod color;
+mod font_face;
+#[cfg(feature = "metadata")]
+pub mod metadata;
+#[cfg(feature = "metadata")]
+mod picker;
+mod ready;
+mod size;
+mod stack;
+#[cfg(any(
+    feature = "svg-outlined",
+    feature = "svg-rounded",
+    feature = "svg-sharp"
+))]
+mod svg;
+pub mod symbols;
+mod theme;
+
+#[cfg(feature = "embed-font")]
+mod embedded {
+    include!(concat!(env!("OUT_DIR"), "/embedded.rs"));
+}
+#[cfg(feature = "embed-font")]
+pub use embedded::EMBEDDED_FONT;
+
+pub use animation::{MaterialIconAnimation, MaterialIconAnimationKind};
+pub use badge::MaterialIconBadge;
+pub use button::{MaterialIconButton, MaterialIconButtonKind, MaterialIconButtonProps};
+pub use color::{color_palette, ColorRole, CssColor, ParseColorError};
+pub use font_face::{FontAxes, FontDisplay, SelfHostedFont};
+#[cfg(feature = "metadata")]
+pub use picker::{MaterialIconPicker, MaterialIconPickerProps};
+pub use ready::use_material_symbols_ready;
+pub use size::MaterialIconSize;
+pub use stack::{
+    MaterialIconLayer, MaterialIconLayerProps, MaterialIconStack, MaterialIconStackProps,
+};
+pub use symbols::{codepoint, is_directional, Symbol};
+pub use theme::{use_icon_theme, IconTheme, MaterialIconTheme, MaterialIconThemeProps};
+
+/// Checks a symbol name at compile time
+///
+/// Expands to the matching constant in the [`symbols`] module,
+/// so it can be used wherever a name is expected.
+/// A misspelled name fails to compile with a suggestion:
+///
+/// ```text
+/// error: unknown symbol `arow_back`, did you mean `arrow_back`?
+/// ```
+///
+/// Requires the `macros` feature, which is enabled by default.
+#[cfg(feature = "macros")]
+pub use dioxus_material_symbols_macros::icon;
+
 /// Props for the [`MaterialIconStylesheet`](MaterialIconStylesheet) component
-#[derive(Props, PartialEq)]
-pub struct MaterialIconStylesheetProps<'a> {
+#[derive(Props, Clone, PartialEq)]
+pub struct MaterialIconStylesheetProps {
     /// Variant prop for the [`MaterialIconStylesheet`](MaterialIconStylesheet) component
     ///
     /// See [`MaterialIconVariant`](MaterialIconVariant) for more information.
     #[props(default = MaterialIconVariant::Rounded)]
-    pub variant: MaterialIconVariant<'a>,
+    pub variant: MaterialIconVariant,
+    /// Loads all of these variants instead of [`variant`](Self::variant)
+    ///
+    /// Use this to mix icons of different styles on the same page,
+    /// see [`MaterialIconProps::variant`](MaterialIconProps::variant).
+    ///
+    /// Optional
+    pub variants: Option<Vec<MaterialIconVariant>>,
+    /// How the icons are displayed while the font is loading
+    ///
+    /// See [`FontDisplay`](FontDisplay) for more information.
+    ///
+    /// Optional, uses `block` for Google Fonts and the embedded font by default,
+    /// and the [`display`](SelfHostedFont::display) of self-hosted fonts
+    pub display: Option<FontDisplay>,
 }
 
 /// Variants (also called categories) of the Material Icon font
 ///
 /// See all variants [here](https://fonts.google.com/icons).
-#[derive(PartialEq)]
-pub enum MaterialIconVariant<'a> {
+#[derive(PartialEq, Clone, Debug)]
+pub enum MaterialIconVariant {
     /// Outlined
     Outlined,
     /// Rounded
@@ -30,67 +101,418 @@ pub enum MaterialIconVariant<'a> {
     Sharp,
     /// Self hosted font file
     ///
-    /// Provide an url to a ttf or otf file.
-    /// You can download the files [here](https://github.com/google/material-design-icons/tree/master/font).
-    SelfHosted(&'a str),
+    /// See [`SelfHostedFont`](SelfHostedFont) for the configuration.
+    SelfHosted(SelfHostedFont),
+    /// Font file embedded in the binary
+    ///
+    /// Renders icons without any files on disk or network access, e.g. in desktop apps.
+    /// The font is included as a data URI, so prefer a [subset](https://github.com/kualta/diom/tree/main/subset)
+    /// of the full font.
+    ///
+    /// The font is loaded as [`MaterialIconStyle::Outlined`](MaterialIconStyle::Outlined).
+    ///
+    /// Requires the `embed-font` feature. By default, the font bundled with this crate is embedded.
+    /// It is the static classic Material Icons font, so with it the `fill`, `weight`, `grade` and `optical_size`
+    /// props have no effect, and symbols which only exist in Material Symbols render as their name.
+    /// Set `DIOXUS_MATERIAL_SYMBOLS_EMBEDDED_FONT` to the absolute path of a font file at build time to embed another one,
+    /// e.g. a subset of the Material Symbols Outlined font.
+    #[cfg(feature = "embed-font")]
+    Embedded,
+}
+
+impl MaterialIconVariant {
+    /// Returns the style of this variant, if it is served by Google Fonts
+    fn google_fonts_style(&self) -> Option<MaterialIconStyle> {
+        match self {
+            MaterialIconVariant::Outlined => Some(MaterialIconStyle::Outlined),
+            MaterialIconVariant::Rounded => Some(MaterialIconStyle::Rounded),
+            MaterialIconVariant::Sharp => Some(MaterialIconStyle::Sharp),
+            _ => None,
+        }
+    }
+
+    /// Returns the `@font-face` rule and style class, if this variant is not served by Google Fonts
+    fn font_face_css(&self, display: Option<FontDisplay>) -> Option<String> {
+        match self {
+            MaterialIconVariant::SelfHosted(font) => Some(font_face::css(
+                font.style,
+                &font.src(),
+                display.unwrap_or(font.display),
+                font.axes,
+            )),
+            #[cfg(feature = "embed-font")]
+            MaterialIconVariant::Embedded => Some(font_face::css(
+                MaterialIconStyle::Outlined,
+                embedded::EMBEDDED_FONT_SRC,
+                display.unwrap_or(FontDisplay::Block),
+                FontAxes::default(),
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// Styles of Material Symbols
+///
+/// Each style is a separate font family with its own CSS class.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum MaterialIconStyle {
+    /// Outlined
+    Outlined,
+    /// Rounded
+    Rounded,
+    /// Sharp
+    Sharp,
+}
+
+impl MaterialIconStyle {
+    /// CSS class of the style (e.g. `material-symbols-rounded`)
+    pub fn class(&self) -> &'static str {
+        match self {
+            MaterialIconStyle::Outlined => "material-symbols-outlined",
+            MaterialIconStyle::Rounded => "material-symbols-rounded",
+            MaterialIconStyle::Sharp => "material-symbols-sharp",
+        }
+    }
+
+    /// Font family of the style (e.g. `Material Symbols Rounded`)
+    pub fn family(&self) -> &'static str {
+        match self {
+            MaterialIconStyle::Outlined => "Material Symbols Outlined",
+            MaterialIconStyle::Rounded => "Material Symbols Rounded",
+            MaterialIconStyle::Sharp => "Material Symbols Sharp",
+        }
+    }
+}
+
+/// Returns the Google Fonts stylesheet url which loads all given styles with their variable font axes
+fn google_fonts_url(
+    styles: impl Iterator<Item = MaterialIconStyle>,
+    display: Option<FontDisplay>,
+) -> Option<String> {
+    let families: Vec<String> = styles
+        .map(|style| {
+            format!(
+                "family={}:opsz,wght,FILL,GRAD@20..48,100..700,0..1,-50..200",
+                style.family().replace(' ', "+")
+            )
+        })
+        .collect();
+    let display = display
+        .map(|display| format!("&display={}", display.to_css()))
+        .unwrap_or_default();
+    (!families.is_empty()).then(|| {
+        format!(
+            "https://fonts.googleapis.com/css2?{}{display}",
+            families.join("&")
+        )
+    })
+}
+
+/// Returns the Google Fonts stylesheet url of the variants which are served by Google Fonts
+fn stylesheet_url(
+    variants: &[MaterialIconVariant],
+    display: Option<FontDisplay>,
+) -> Option<String> {
+    google_fonts_url(
+        variants
+            .iter()
+            .filter_map(MaterialIconVariant::google_fonts_style),
+        display,
+    )
+}
+
+/// Returns the `<link>` tag which loads the variants served by Google Fonts
+///
+/// This is the link rendered by the [`MaterialIconStylesheet`](MaterialIconStylesheet) component,
+/// for putting it into the document head when rendering on the server.
+/// Returns `None` if none of the variants are served by Google Fonts.
+/// See [`MaterialIconStylesheetProps::display`](MaterialIconStylesheetProps::display) for the `display` argument.
+///
+/// ```
+/// # use dioxus_material_symbols::{stylesheet_link, MaterialIconVariant};
+/// let link = stylesheet_link(&[MaterialIconVariant::Rounded], None).unwrap();
+/// assert!(link.starts_with("<link href=\"https://fonts.googleapis.com/css2?family=Material+Symbols+Rounded"));
+/// ```
+pub fn stylesheet_link(
+    variants: &[MaterialIconVariant],
+    display: Option<FontDisplay>,
+) -> Option<String> {
+    stylesheet_url(variants, display).map(|href| {
+        format!(
+            "<link href=\"{}\" rel=\"stylesheet\">",
+            href.replace('&', "&amp;")
+        )
+    })
+}
+
+/// Returns the CSS of the given variants
+///
+/// This is the content of the `<style>` tag rendered by the [`MaterialIconStylesheet`](MaterialIconStylesheet) component,
+/// for putting it into the document head when rendering on the server.
+/// It contains the `@font-face` rules of self-hosted and embedded variants and the styles of the components
+/// of this crate, e.g. for mirroring icons or the [`MaterialIconButton`](MaterialIconButton).
+/// Variants served by Google Fonts are loaded by the [`stylesheet_link`] instead.
+pub fn stylesheet_css(variants: &[MaterialIconVariant], display: Option<FontDisplay>) -> String {
+    let mut css: Vec<String> = variants
+        .iter()
+        .filter_map(|variant| variant.font_face_css(display))
+        .collect();
+    css.push(include_str!("./icon-styles.css").to_string());
+    css.push(animation::CSS.to_string());
+    css.push(badge::CSS.to_string());
+    css.push(button::CSS.to_string());
+    css.push(stack::CSS.to_string());
+    #[cfg(feature = "metadata")]
+    css.push(picker::CSS.to_string());
+    css.join("\n")
 }
 
 /// Stylesheet component
 ///
 /// This component includes the Material Symbols stylesheet.
 /// This is required to render all Material Symbols correctly.
+/// It also includes the styles of the components of this crate, e.g. for mirroring icons or the [`MaterialIconButton`](MaterialIconButton).
 ///
-/// You can provide a variant as a prop (e.g. Rounded).
+/// You can provide a variant as a prop (e.g. Rounded), or several variants to mix them on the same page.
 /// When you want to provide your own self-hosted font file,
 /// please use [`MaterialIconVariant::SelfHosted`](MaterialIconVariant::SelfHosted) and pass the
-/// file path or url to your .ttf or .otf file to it.
+/// file paths or urls of your font files to it.
 /// See the [button example](https://github.com/lennartkloock/dioxus-material-symbols/blob/main/examples/button.rs).
-pub fn MaterialIconStylesheet<'a>(cx: Scope<'a, MaterialIconStylesheetProps<'a>>) -> Element<'a> {
-    let href = match &cx.props.variant {
-        MaterialIconVariant::SelfHosted(file) => {
-            return cx.render(rsx!(
-                style { format!(include_str!("./self-hosted-styles.css"), file) }
-            ));
-        }
-        MaterialIconVariant::Outlined => {
-            "https://fonts.googleapis.com/icon?family=Material+Symbols+Outlined"
-        }
-        MaterialIconVariant::Rounded => {
-            "https://fonts.googleapis.com/icon?family=Material+Symbols+Rounded"
-        }
-        MaterialIconVariant::Sharp => {
-            "https://fonts.googleapis.com/icon?family=Material+Symbols+Sharp"
+///
+/// When rendering on the server, the stylesheet can also be put into the document head
+/// with [`stylesheet_link`] and [`stylesheet_css`].
+#[component]
+pub fn MaterialIconStylesheet(props: MaterialIconStylesheetProps) -> Element {
+    let variants = props
+        .variants
+        .as_deref()
+        .unwrap_or(std::slice::from_ref(&props.variant));
+    let href = stylesheet_url(variants, props.display);
+    let css = stylesheet_css(variants, props.display);
+    rsx!(
+        if let Some(href) = href {
+            link { href, rel: "stylesheet" }
         }
-    };
-    cx.render(rsx!(link {
-        href: "{href}",
-        rel: "stylesheet"
-    }))
+        style { {css} }
+    )
 }
 
 /// Props for the [`MaterialIcon`](MaterialIcon) component
-#[derive(Props, PartialEq)]
-pub struct MaterialIconProps<'a> {
-    /// Name (e.g. `home`)
+#[derive(Props, Clone, PartialEq)]
+pub struct MaterialIconProps {
+    /// Name (e.g. `home` or [`symbols::HOME`])
     ///
+    /// Prefer the constants in the [`symbols`] module, which are checked at compile time.
     /// Browse all symbols [here](https://fonts.google.com/symbols?selected=Material+Symbols).
-    pub name: &'a str,
-    /// Size in pixels
+    #[props(into)]
+    pub name: String,
+    /// Size (e.g. `24`, `"1.5rem"` or [`MaterialIconSize::DP_20`](MaterialIconSize::DP_20))
+    ///
+    /// See [`MaterialIconSize`](MaterialIconSize) for more information.
+    /// Icons without a size inherit the font size of their parent.
     ///
     /// Optional
-    pub size: Option<u32>,
+    #[props(into)]
+    pub size: Option<MaterialIconSize>,
     /// Color
     ///
     /// Optional
     #[props(into)]
-    pub color: Option<MaterialIconColor<'a>>,
+    pub color: Option<MaterialIconColor>,
+    /// Fill (`FILL` axis)
+    ///
+    /// Renders the filled version of the symbol when `true`.
+    ///
+    /// Optional
+    pub fill: Option<bool>,
+    /// Weight (`wght` axis), from 100 to 700
+    ///
+    /// Optional
+    pub weight: Option<u16>,
+    /// Grade (`GRAD` axis), from -50 to 200
+    ///
+    /// Negative values make the symbol thinner, e.g. for light symbols on a dark background.
+    ///
+    /// Optional
+    pub grade: Option<i16>,
+    /// Optical size (`opsz` axis), from 20 to 48
+    ///
+    /// By default, the optical size follows the rendered size in pixels, if the font has the `opsz` axis.
+    ///
+    /// Optional
+    pub optical_size: Option<u16>,
+    /// Style of the symbol
+    ///
+    /// The style has to be loaded by the [`MaterialIconStylesheet`](MaterialIconStylesheet).
+    ///
+    /// Optional, uses the style of the [`MaterialIconTheme`](MaterialIconTheme)
+    /// or whichever style was loaded last by default
+    pub variant: Option<MaterialIconStyle>,
+    /// Rendering mode
+    ///
+    /// See [`MaterialIconMode`](MaterialIconMode) for more information.
+    #[props(default)]
+    pub mode: MaterialIconMode,
+    /// Hides the icon until the icon font is loaded, while reserving its space
+    ///
+    /// Prevents the name from showing up as text on slow connections.
+    /// See [`use_material_symbols_ready`] for how the font is checked. Ignored in SVG modes.
+    #[props(default)]
+    pub hide_until_loaded: bool,
+    /// Mirrors the icon horizontally
+    ///
+    /// By default, [directional](symbols::DIRECTIONAL) symbols (e.g. `arrow_forward` or `send`) are mirrored
+    /// in right-to-left layouts, i.e. inside an element with `dir="rtl"`, and all other symbols are left alone.
+    /// `true` always mirrors the icon, `false` never does.
+    ///
+    /// Optional
+    pub mirror: Option<bool>,
+    /// Badge shown on the top end corner of the icon (e.g. `MaterialIconBadge::count(3)`)
+    ///
+    /// See [`MaterialIconBadge`](MaterialIconBadge) for more information.
+    ///
+    /// Optional
+    pub badge: Option<MaterialIconBadge>,
+    /// Animation of the icon (e.g. `MaterialIconAnimation::spin()`)
+    ///
+    /// See [`MaterialIconAnimation`](MaterialIconAnimation) for more information.
+    ///
+    /// Optional
+    pub animation: Option<MaterialIconAnimation>,
+    /// Accessible label (e.g. `Settings`)
+    ///
+    /// Icons without a label are decorative and hidden from assistive technology.
+    /// Labelled icons are announced as images with this label.
+    ///
+    /// Optional
+    #[props(into)]
+    pub label: Option<String>,
+    /// Tooltip shown on hover
+    ///
+    /// Optional
+    #[props(into)]
+    pub title: Option<String>,
+    /// Click handler
+    ///
+    /// Makes the icon interactive, which requires a [`label`](Self::label).
+    /// Debug builds log a warning if it is missing.
+    /// The icon can't be focused or activated with the keyboard, so prefer a
+    /// [`MaterialIconButton`](MaterialIconButton) for actions.
+    ///
+    /// Optional
+    pub onclick: Option<EventHandler<MouseEvent>>,
+    /// Additional CSS classes
+    ///
+    /// Added to the generated classes of the icon.
+    ///
+    /// Optional
+    #[props(into)]
+    pub class: Option<String>,
+    /// Additional inline styles
+    ///
+    /// Appended to the generated styles, so they take precedence over them.
+    ///
+    /// Optional
+    #[props(into)]
+    pub style: Option<String>,
+    /// Any other attributes (e.g. `id`, `tabindex` or `"data-*"`), passed through to the icon element
+    #[props(extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+}
+
+impl MaterialIconProps {
+    /// Converts the variable font axes to their corresponding `font-variation-settings` value
+    ///
+    /// Returns `None` if no axis was set.
+    pub fn font_variation_settings(&self) -> Option<String> {
+        let axes: Vec<String> = [
+            ("FILL", self.fill.map(i32::from)),
+            ("wght", self.weight.map(i32::from)),
+            ("GRAD", self.grade.map(i32::from)),
+            ("opsz", self.optical_size.map(i32::from)),
+        ]
+        .into_iter()
+        .filter_map(|(axis, value)| value.map(|v| format!("'{axis}' {v}")))
+        .collect();
+        (!axes.is_empty()).then(|| axes.join(", "))
+    }
+}
+
+/// Rendering modes of the [`MaterialIcon`](MaterialIcon) component
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum MaterialIconMode {
+    /// Renders the name as text, which the icon font displays as the symbol
+    ///
+    /// Requires the [`MaterialIconStylesheet`](MaterialIconStylesheet) component.
+    #[default]
+    Ligature,
+    /// Renders the codepoint of the symbol as text, which the icon font displays as the symbol
+    ///
+    /// Unlike the name, the codepoint is a single character, so there is no wide flash of text
+    /// while the font is loading. The codepoints are the ones of the bundled table, see [`codepoint`].
+    /// Unknown symbols fall back to the ligature rendering.
+    /// Requires the [`MaterialIconStylesheet`](MaterialIconStylesheet) component.
+    Codepoint,
+    /// Renders an inline SVG of the outlined style, which needs no icon font
+    ///
+    /// The variable font axes are ignored in this mode.
+    /// Requires the `svg-outlined` feature.
+    #[cfg(feature = "svg-outlined")]
+    SvgOutlined,
+    /// Renders an inline SVG of the rounded style, which needs no icon font
+    ///
+    /// The variable font axes are ignored in this mode.
+    /// Requires the `svg-rounded` feature.
+    #[cfg(feature = "svg-rounded")]
+    SvgRounded,
+    /// Renders an inline SVG of the sharp style, which needs no icon font
+    ///
+    /// The variable font axes are ignored in this mode.
+    /// Requires the `svg-sharp` feature.
+    #[cfg(feature = "svg-sharp")]
+    SvgSharp,
+}
+
+impl MaterialIconMode {
+    /// Whether this is one of the SVG modes, which need no icon font
+    fn is_svg(&self) -> bool {
+        !matches!(
+            self,
+            MaterialIconMode::Ligature | MaterialIconMode::Codepoint
+        )
+    }
+
+    /// Returns the SVG path data of a symbol, if this is an SVG mode and the symbol exists
+    #[allow(unused_variables)]
+    fn svg_path(&self, name: &str) -> Option<&'static str> {
+        match self {
+            MaterialIconMode::Ligature | MaterialIconMode::Codepoint => None,
+            #[cfg(feature = "svg-outlined")]
+            MaterialIconMode::SvgOutlined => svg::path(svg::OUTLINED, name),
+            #[cfg(feature = "svg-rounded")]
+            MaterialIconMode::SvgRounded => svg::path(svg::ROUNDED, name),
+            #[cfg(feature = "svg-sharp")]
+            MaterialIconMode::SvgSharp => svg::path(svg::SHARP, name),
+        }
+    }
+
+    /// Returns the text rendered for a symbol in the font based modes
+    fn text(&self, name: &str) -> String {
+        match self {
+            MaterialIconMode::Codepoint => codepoint(name)
+                .map(String::from)
+                .unwrap_or_else(|| name.to_string()),
+            _ => name.to_string(),
+        }
+    }
 }
 
 /// Colors of Material Symbols
 ///
 /// As described [here](https://developers.google.com/fonts/docs/material_symbols#styling_symbols_in_material_design).
-#[derive(PartialEq)]
-pub enum MaterialIconColor<'a> {
+#[derive(PartialEq, Clone, Debug)]
+pub enum MaterialIconColor {
     /// For using symbols as black on a light background.
     Dark,
     /// For using symbols as black on a light background.
@@ -99,27 +521,90 @@ pub enum MaterialIconColor<'a> {
     Light,
     /// For using symbols as white on a dark background.
     LightInactive,
-    /// Custom color, any valid CSS color
+    /// Material 3 color role, which follows the theme of the app
     ///
-    /// E.g.: `#0000ff` or `red`
-    Custom(&'a str),
+    /// See [`ColorRole`](ColorRole) for more information.
+    Role(ColorRole),
+    /// Custom color
+    ///
+    /// See [`CssColor`](CssColor) for the supported colors.
+    Custom(CssColor),
+}
+
+/// Parses a CSS color, e.g. `#0000ff` or `red`
+///
+/// Invalid colors are logged as a warning through `tracing` and replaced by `currentColor`.
+/// Parse the string into a [`CssColor`](CssColor) to handle the error instead.
+impl From<&str> for MaterialIconColor {
+    fn from(value: &str) -> Self {
+        let color = value.parse().unwrap_or_else(|error| {
+            tracing::warn!("{error}, using `currentColor` instead");
+            CssColor::CurrentColor
+        });
+        Self::Custom(color)
+    }
+}
+
+impl From<String> for MaterialIconColor {
+    fn from(value: String) -> Self {
+        value.as_str().into()
+    }
 }
 
-impl<'a> From<&'a str> for MaterialIconColor<'a> {
-    fn from(value: &'a str) -> Self {
+impl From<CssColor> for MaterialIconColor {
+    fn from(value: CssColor) -> Self {
         Self::Custom(value)
     }
 }
 
-impl MaterialIconColor<'_> {
+impl From<ColorRole> for MaterialIconColor {
+    fn from(value: ColorRole) -> Self {
+        Self::Role(value)
+    }
+}
+
+#[doc(hidden)]
+pub struct OptionColorFromMarker;
+
+// Allows passing strings to optional color props, e.g. `color: "blue"`
+impl SuperFrom<&str, OptionColorFromMarker> for Option<MaterialIconColor> {
+    fn super_from(input: &str) -> Self {
+        Some(input.into())
+    }
+}
+
+impl SuperFrom<String, OptionColorFromMarker> for Option<MaterialIconColor> {
+    fn super_from(input: String) -> Self {
+        Some(input.into())
+    }
+}
+
+// Allows passing color roles to optional color props, e.g. `color: ColorRole::Primary`
+impl SuperFrom<ColorRole, OptionColorFromMarker> for Option<MaterialIconColor> {
+    fn super_from(input: ColorRole) -> Self {
+        Some(input.into())
+    }
+}
+
+// Allows passing parsed colors to optional color props, e.g. `color: CssColor::rgb(0, 0, 255)`
+impl SuperFrom<CssColor, OptionColorFromMarker> for Option<MaterialIconColor> {
+    fn super_from(input: CssColor) -> Self {
+        Some(input.into())
+    }
+}
+
+impl MaterialIconColor {
     /// Converts the color to its corresponding CSS color
-    pub fn to_css_color(&self) -> &str {
+    ///
+    /// Custom colors are written in their canonical form, so the value is always safe to put into a `style` attribute.
+    pub fn to_css_color(&self) -> String {
         match self {
-            MaterialIconColor::Dark => "rgba(0, 0, 0, 0.54)",
-            MaterialIconColor::DarkInactive => "rgba(0, 0, 0, 0.26)",
-            MaterialIconColor::Light => "rgba(255, 255, 255, 1)",
-            MaterialIconColor::LightInactive => "rgba(255, 255, 255, 0.3)",
-            MaterialIconColor::Custom(c) => c,
+            MaterialIconColor::Dark => "rgba(0, 0, 0, 0.54)".to_string(),
+            MaterialIconColor::DarkInactive => "rgba(0, 0, 0, 0.26)".to_string(),
+            MaterialIconColor::Light => "rgba(255, 255, 255, 1)".to_string(),
+            MaterialIconColor::LightInactive => "rgba(255, 255, 255, 0.3)".to_string(),
+            MaterialIconColor::Role(role) => role.to_css_color().to_string(),
+            MaterialIconColor::Custom(color) => color.to_string(),
         }
     }
 }
@@ -127,24 +612,141 @@ impl MaterialIconColor<'_> {
 /// Material Icon component
 ///
 /// This component can be used to render a Material Icon.
-pub fn MaterialIcon<'a>(cx: Scope<'a, MaterialIconProps<'a>>) -> Element<'a> {
-    // The `font-size` attribute has to be explicitly declared as `inherit` because the stylesheet sets a default of 24px
-    let css_size = cx
-        .props
-        .size
-        .map(|s| format!("{s}px"))
-        .unwrap_or_else(|| "inherit".to_string());
-    let css_color = cx
-        .props
+///
+/// In one of the SVG [modes](MaterialIconMode), unknown symbols render as an empty box of the icon size,
+/// as there may be no icon font to render their name. Debug builds log a warning for them.
+///
+/// Icons are decorative (`aria-hidden`) unless they have a [`label`](MaterialIconProps::label).
+///
+/// Props that are `None` fall back to the closest [`MaterialIconTheme`](MaterialIconTheme).
+#[component]
+pub fn MaterialIcon(props: MaterialIconProps) -> Element {
+    let theme = use_icon_theme();
+    let props = MaterialIconProps {
+        size: props.size.or(theme.size),
+        color: props.color.or(theme.color),
+        fill: props.fill.or(theme.fill),
+        weight: props.weight.or(theme.weight),
+        grade: props.grade.or(theme.grade),
+        optical_size: props.optical_size.or(theme.optical_size),
+        variant: props.variant.or(theme.variant),
+        ..props
+    };
+    if cfg!(debug_assertions) {
+        let unlabelled = props.onclick.is_some() && props.label.is_none();
+        let missing_svg = props.mode.is_svg() && props.mode.svg_path(&props.name).is_none();
+        let name = &props.name;
+        use_hook(|| {
+            if missing_svg {
+                tracing::warn!(
+                    "MaterialIcon `{name}` has no SVG path data, it is rendered as an empty box"
+                );
+            }
+            if unlabelled {
+                tracing::warn!("Interactive MaterialIcon `{name}` has no label, set the `label` prop to make it accessible. For keyboard access, use a MaterialIconButton instead");
+            }
+        });
+    }
+    let role = props.label.is_some().then_some("img");
+    // Interactive icons must not be hidden, even without a label
+    let aria_hidden = (props.label.is_none() && props.onclick.is_none()).then_some("true");
+    let onclick = move |event| {
+        if let Some(handler) = &props.onclick {
+            handler.call(event);
+        }
+    };
+    let css_color = props
         .color
         .as_ref()
         .map(|c| format!("color: {};", c.to_css_color()))
         .unwrap_or_default();
-    cx.render(rsx!(
+    let (class, style, content) = if props.mode.is_svg() {
+        let d = props.mode.svg_path(&props.name);
+        let css_size = props
+            .size
+            .as_ref()
+            .map(|s| format!("font-size: {}; ", s.to_css()))
+            .unwrap_or_default();
+        (
+            "material-symbols-svg".to_string(),
+            format!("display: inline-flex; vertical-align: middle; {css_size}{css_color}"),
+            rsx!(
+                svg {
+                    width: "1em",
+                    height: "1em",
+                    view_box: "0 0 24 24",
+                    fill: "currentColor",
+                    if let Some(d) = d {
+                        path { d }
+                    }
+                }
+            ),
+        )
+    } else {
+        // The `font-size` attribute has to be explicitly declared as `inherit` because the stylesheet sets a default of 24px
+        let css_size = props
+            .size
+            .as_ref()
+            .map(MaterialIconSize::to_css)
+            .unwrap_or_else(|| "inherit".to_string());
+        let css_variation = props
+            .font_variation_settings()
+            .map(|v| format!("font-variation-settings: {v};"))
+            .unwrap_or_default();
+        // Only starts checking and subscribes to the loading state if the icon depends on it
+        let css_hidden = if props.hide_until_loaded && !ready::fonts_ready()() {
+            " width: 1em; overflow: hidden; visibility: hidden;"
+        } else {
+            ""
+        };
+        let style_class = match props.variant {
+            Some(style) => style.class(),
+            None => "material-symbols-outlined material-symbols-rounded material-symbols-sharp",
+        };
+        (
+            format!("material-symbols {style_class}"),
+            format!(
+                "font-size: {css_size}; {css_color} {css_variation} user-select: none;{css_hidden}"
+            ),
+            rsx!({ props.mode.text(&props.name) }),
+        )
+    };
+    let class = match props.mirror {
+        Some(true) => format!("{class} material-symbols-mirrored"),
+        None if is_directional(&props.name) => format!("{class} material-symbols-directional"),
+        _ => class,
+    };
+    let (class, style) = match &props.animation {
+        Some(animation) => (
+            format!("{class} {}", animation.class()),
+            format!("{style} {}", animation.style()),
+        ),
+        None => (class, style),
+    };
+    let class = match &props.class {
+        Some(extra) => format!("{class} {extra}"),
+        None => class,
+    };
+    let style = match &props.style {
+        Some(extra) => format!("{style} {extra}"),
+        None => style,
+    };
+    let icon = rsx!(
         span {
-            class: "material-symbols material-symbols-outlined material-symbols-rounded material-symbols-sharp md-48",
-            style: "font-size: {css_size}; {css_color} user-select: none;",
-            cx.props.name
+            class,
+            style,
+            role,
+            aria_label: props.label.clone(),
+            aria_hidden,
+            title: props.title.clone(),
+            onclick,
+            ..props.attributes,
+            {content}
         }
-    ))
+    );
+    // The badge is a sibling of the icon, so it isn't mirrored along with it
+    match &props.badge {
+        Some(badge) => badge.render(icon),
+        None => icon,
+    }
 }
@@ -0,0 +1,130 @@
+//! Searchable metadata of all symbols
+//!
+//! Requires the `metadata` feature.
+//!
+//! The metadata is compiled into the binary at build time, so searching works fully offline.
+//! Categories, tags and popularity are read from `data/metadata.json`, which is not part of the repository
+//! and has to be downloaded from <https://fonts.google.com/metadata/icons> first; the build fails without it.
+//! Set `DIOXUS_MATERIAL_SYMBOLS_METADATA` to the absolute path of a copy elsewhere to use it instead.
+//! Symbols missing from the file have no categories or tags, but can still be found by their name.
+//!
+//! ```
+//! use dioxus_material_symbols::metadata;
+//!
+//! let results = metadata::search("arrow back", None);
+//! assert_eq!(results[0].name, "arrow_back");
+//! ```
+
+/// Metadata of a symbol
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct IconMetadata {
+    /// Name, e.g. `arrow_back`
+    pub name: &'static str,
+    /// Codepoint in the Private Use Area of the font
+    pub codepoint: char,
+    /// Categories, e.g. `navigation`
+    pub categories: &'static [&'static str],
+    /// Synonyms used for searching
+    pub tags: &'static [&'static str],
+    /// Popularity on Google Fonts, higher is more popular
+    pub popularity: u32,
+}
+
+/// Metadata of all symbols, sorted by name
+static ICONS: &[IconMetadata] = include!(concat!(env!("OUT_DIR"), "/metadata.rs"));
+
+/// Returns the metadata of all symbols, sorted by name
+pub fn all() -> &'static [IconMetadata] {
+    ICONS
+}
+
+/// Returns the metadata of a symbol
+pub fn get(name: &str) -> Option<&'static IconMetadata> {
+    ICONS
+        .binary_search_by(|icon| icon.name.cmp(name))
+        .ok()
+        .map(|i| &ICONS[i])
+}
+
+/// Returns all categories, sorted by name
+pub fn categories() -> Vec<&'static str> {
+    let mut categories: Vec<&'static str> = ICONS
+        .iter()
+        .flat_map(|icon| icon.categories.iter().copied())
+        .collect();
+    categories.sort_unstable();
+    categories.dedup();
+    categories
+}
+
+/// Searches symbols by name and tags, optionally only in one category
+///
+/// Every word of the query has to match the name, a tag or a category of a symbol.
+/// Results are ranked by how well they match, then by popularity.
+/// An empty query returns all symbols of the category, ranked by popularity.
+pub fn search(query: &str, category: Option<&str>) -> Vec<&'static IconMetadata> {
+    let query = query.to_lowercase();
+    let words: Vec<&str> = query
+        .split(|c: char| c.is_whitespace() || c == '_' || c == '-')
+        .filter(|word| !word.is_empty())
+        .collect();
+    let mut results: Vec<(u32, &'static IconMetadata)> = ICONS
+        .iter()
+        .filter(|icon| category.is_none_or(|category| icon.categories.contains(&category)))
+        .filter_map(|icon| Some((score(icon, &query, &words)?, icon)))
+        .collect();
+    results.sort_by(|(a_score, a), (b_score, b)| {
+        b_score
+            .cmp(a_score)
+            .then(b.popularity.cmp(&a.popularity))
+            .then(a.name.cmp(b.name))
+    });
+    results.into_iter().map(|(_, icon)| icon).collect()
+}
+
+/// Returns how well a symbol matches the query, or `None` if any word doesn't match
+fn score(icon: &IconMetadata, query: &str, words: &[&str]) -> Option<u32> {
+    let joined = words.join("_");
+    let mut score = match icon.name {
+        name if name == joined => 1000,
+        name if name.starts_with(&joined) => 500,
+        _ => 0,
+    };
+    for word in words {
+        score += word_score(icon, word)?;
+    }
+    // Matches the whole query as a tag, e.g. "thumbs up"
+    if icon.tags.iter().any(|tag| tag.eq_ignore_ascii_case(query)) {
+        score += 200;
+    }
+    Some(score)
+}
+
+/// Returns how well a symbol matches a single word of the query
+fn word_score(icon: &IconMetadata, word: &str) -> Option<u32> {
+    let name_parts = || icon.name.split('_');
+    if name_parts().any(|part| part == word) {
+        Some(100)
+    } else if name_parts().any(|part| part.starts_with(word)) {
+        Some(60)
+    } else if icon.tags.iter().any(|tag| tag.eq_ignore_ascii_case(word)) {
+        Some(40)
+    } else if icon
+        .tags
+        .iter()
+        .any(|tag| starts_with_ignore_case(tag, word))
+    {
+        Some(20)
+    } else if icon.name.contains(word) {
+        Some(10)
+    } else if icon.categories.contains(&word) {
+        Some(5)
+    } else {
+        None
+    }
+}
+
+fn starts_with_ignore_case(text: &str, prefix: &str) -> bool {
+    text.get(..prefix.len())
+        .is_some_and(|start| start.eq_ignore_ascii_case(prefix))
+}
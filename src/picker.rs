@@ -0,0 +1,322 @@
+//! Searchable icon picker
+
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use dioxus::prelude::*;
+
+use crate::metadata::{self, IconMetadata};
+use crate::{MaterialIcon, MaterialIconMode, MaterialIconSize, MaterialIconStyle};
+
+/// Styles of all icon pickers, included by the [`MaterialIconStylesheet`](crate::MaterialIconStylesheet)
+pub(crate) const CSS: &str = include_str!("./picker-styles.css");
+
+/// Rows rendered above and below the visible part of the grid
+const OVERSCAN_ROWS: usize = 2;
+
+/// Props for the [`MaterialIconPicker`](MaterialIconPicker) component
+#[derive(Props, Clone, PartialEq)]
+pub struct MaterialIconPickerProps {
+    /// Called with the name of the symbol when one is picked
+    pub onselect: EventHandler<String>,
+    /// Name of the currently selected symbol
+    ///
+    /// Optional
+    #[props(into)]
+    pub selected: Option<String>,
+    /// Number of columns of the grid
+    #[props(default = 8)]
+    pub columns: usize,
+    /// Number of visible rows of the grid
+    #[props(default = 6)]
+    pub rows: usize,
+    /// Width and height of a cell in pixels
+    #[props(default = 48)]
+    pub cell_size: u32,
+    /// Placeholder of the search box
+    #[props(into, default = "Search icons".to_string())]
+    pub placeholder: String,
+    /// Style of the symbols
+    ///
+    /// See [`MaterialIconProps::variant`](crate::MaterialIconProps::variant).
+    ///
+    /// Optional
+    pub variant: Option<MaterialIconStyle>,
+    /// Rendering mode of the symbols
+    ///
+    /// See [`MaterialIconMode`](MaterialIconMode) for more information.
+    #[props(default)]
+    pub mode: MaterialIconMode,
+}
+
+/// Movement of the active cell triggered by a key
+#[derive(PartialEq, Debug)]
+enum Navigation {
+    By(isize),
+    First,
+    Last,
+    Select,
+}
+
+impl Navigation {
+    /// Returns the navigation of a key, or `None` if the key doesn't navigate
+    ///
+    /// `Left` and `Right` are only handled in the grid, so they can move the cursor of the search box.
+    fn from_key(key: &Key, columns: usize, rows: usize, in_grid: bool) -> Option<Navigation> {
+        let columns = columns as isize;
+        let page = columns * rows as isize;
+        match key {
+            Key::ArrowLeft if in_grid => Some(Navigation::By(-1)),
+            Key::ArrowRight if in_grid => Some(Navigation::By(1)),
+            Key::ArrowUp => Some(Navigation::By(-columns)),
+            Key::ArrowDown => Some(Navigation::By(columns)),
+            Key::PageUp => Some(Navigation::By(-page)),
+            Key::PageDown => Some(Navigation::By(page)),
+            Key::Home if in_grid => Some(Navigation::First),
+            Key::End if in_grid => Some(Navigation::Last),
+            Key::Enter => Some(Navigation::Select),
+            Key::Character(c) if in_grid && c == " " => Some(Navigation::Select),
+            _ => None,
+        }
+    }
+
+    /// Returns the index of the cell which is active after the navigation, or picked by [`Select`](Navigation::Select)
+    ///
+    /// Movements past the first or last cell stop at it. `count` must not be zero.
+    fn target(&self, current: usize, count: usize) -> usize {
+        let current = current.min(count - 1);
+        match self {
+            Navigation::By(delta) => current.saturating_add_signed(*delta).min(count - 1),
+            Navigation::First => 0,
+            Navigation::Last => count - 1,
+            Navigation::Select => current,
+        }
+    }
+}
+
+/// Material Icon Picker component
+///
+/// Renders a search box and a category filter over a grid of all symbols.
+/// Only the visible rows of the grid are rendered, so it stays fast with thousands of symbols.
+/// The arrow keys, `Page Up`, `Page Down`, `Home` and `End` move through the grid,
+/// and `Enter` or `Space` pick the active symbol.
+///
+/// Requires the `metadata` feature. The category filter is only shown when the bundled metadata has categories,
+/// see the [`metadata`](crate::metadata) module. Its styles are included by the
+/// [`MaterialIconStylesheet`](crate::MaterialIconStylesheet).
+///
+/// ```
+/// # use dioxus::prelude::*;
+/// # use dioxus_material_symbols::{MaterialIcon, MaterialIconPicker};
+/// #[component]
+/// fn IconSetting() -> Element {
+///     let mut icon = use_signal(|| "home".to_string());
+///     rsx!(
+///         MaterialIcon { name: icon() }
+///         MaterialIconPicker {
+///             selected: icon(),
+///             onselect: move |name| icon.set(name),
+///         }
+///     )
+/// }
+/// ```
+#[component]
+pub fn MaterialIconPicker(props: MaterialIconPickerProps) -> Element {
+    static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+    let id = use_hook(|| NEXT_ID.fetch_add(1, Ordering::Relaxed));
+
+    let mut query = use_signal(String::new);
+    let mut category = use_signal(|| None::<String>);
+    let mut active = use_signal(|| 0usize);
+    let mut scroll_top = use_signal(|| 0.0);
+    let mut grid = use_signal(|| None::<Rc<MountedData>>);
+    let results = use_memo(move || metadata::search(&query.read(), category.read().as_deref()));
+    let categories = use_hook(metadata::categories);
+
+    let columns = props.columns.max(1);
+    let rows = props.rows.max(1);
+    let cell_size = props.cell_size;
+    let onselect = props.onselect;
+    let mut navigate = move |key: &Key, in_grid: bool| -> bool {
+        let Some(navigation) = Navigation::from_key(key, columns, rows, in_grid) else {
+            return false;
+        };
+        let count = results.read().len();
+        if count == 0 {
+            return true;
+        }
+        let target = navigation.target(active(), count);
+        match navigation {
+            Navigation::Select => onselect.call(results.read()[target].name.to_string()),
+            _ => active.set(target),
+        }
+        true
+    };
+
+    let results = results.read();
+    let total_rows = results.len().div_ceil(columns);
+    let active_index = active().min(results.len().saturating_sub(1));
+    let first_row = (scroll_top() / f64::from(cell_size)) as usize;
+    let visible = first_row.saturating_sub(OVERSCAN_ROWS) * columns
+        ..((first_row + rows + OVERSCAN_ROWS) * columns).min(results.len());
+    // The active cell is always rendered, so it can be scrolled into view
+    let active_descendant =
+        (!results.is_empty()).then(|| format!("material-symbols-picker-{id}-{active_index}"));
+    let cells: Vec<(usize, &IconMetadata)> = visible
+        .clone()
+        .chain(
+            (!visible.contains(&active_index) && active_index < results.len())
+                .then_some(active_index),
+        )
+        .map(|i| (i, results[i]))
+        .collect();
+    let is_visible = move |i: usize| {
+        let top = (i / columns) as f64 * f64::from(cell_size);
+        top >= scroll_top()
+            && top + f64::from(cell_size) <= scroll_top() + rows as f64 * f64::from(cell_size)
+    };
+
+    rsx!(
+        div { class: "material-symbols-picker",
+            div { class: "material-symbols-picker-filters",
+                // The search box controls the grid as a combobox, so the active symbol is announced while typing
+                input {
+                    r#type: "search",
+                    role: "combobox",
+                    aria_label: props.placeholder.clone(),
+                    placeholder: props.placeholder,
+                    aria_autocomplete: "list",
+                    aria_controls: "material-symbols-picker-{id}",
+                    aria_expanded: "true",
+                    aria_activedescendant: active_descendant.clone(),
+                    value: query,
+                    oninput: move |event| {
+                        query.set(event.value());
+                        active.set(0);
+                    },
+                    onkeydown: move |event| {
+                        if navigate(&event.key(), false) {
+                            event.prevent_default();
+                        }
+                    },
+                }
+                if !categories.is_empty() {
+                    select {
+                        aria_label: "Category",
+                        onchange: move |event| {
+                            let value = event.value();
+                            category.set((!value.is_empty()).then_some(value));
+                            active.set(0);
+                        },
+                        option { value: "", "All categories" }
+                        for c in categories.iter() {
+                            option { value: *c, selected: category.read().as_deref() == Some(*c), "{c}" }
+                        }
+                    }
+                }
+            }
+            div {
+                id: "material-symbols-picker-{id}",
+                class: "material-symbols-picker-grid",
+                role: "listbox",
+                tabindex: 0,
+                aria_activedescendant: active_descendant,
+                style: "width: {columns as u32 * cell_size}px; height: {rows as u32 * cell_size}px;",
+                onmounted: move |event| grid.set(Some(event.data())),
+                onscroll: move |_| async move {
+                    let Some(grid) = grid() else {
+                        return;
+                    };
+                    if let Ok(offset) = grid.get_scroll_offset().await {
+                        scroll_top.set(offset.y);
+                    }
+                },
+                onkeydown: move |event| {
+                    if navigate(&event.key(), true) {
+                        event.prevent_default();
+                    }
+                },
+                div { style: "position: relative; height: {total_rows as u32 * cell_size}px;",
+                    for (i, icon) in cells {
+                        div {
+                            // Remounts the cell when it becomes active, to scroll it into view
+                            key: "{i}-{i == active_index}",
+                            id: "material-symbols-picker-{id}-{i}",
+                            class: if i == active_index { "material-symbols-picker-cell material-symbols-picker-active" } else { "material-symbols-picker-cell" },
+                            role: "option",
+                            aria_label: icon.name.replace('_', " "),
+                            aria_selected: props.selected.as_deref() == Some(icon.name),
+                            title: icon.name,
+                            style: "top: {(i / columns) as u32 * cell_size}px; left: {(i % columns) as u32 * cell_size}px; width: {cell_size}px; height: {cell_size}px;",
+                            onmounted: move |event| async move {
+                                if i == active_index && !is_visible(i) {
+                                    _ = event.data().scroll_to(ScrollBehavior::Instant).await;
+                                }
+                            },
+                            onclick: move |_| {
+                                active.set(i);
+                                onselect.call(icon.name.to_string());
+                            },
+                            MaterialIcon {
+                                name: icon.name,
+                                size: MaterialIconSize::DP_24,
+                                variant: props.variant,
+                                mode: props.mode,
+                            }
+                        }
+                    }
+                }
+                if results.is_empty() {
+                    div { class: "material-symbols-picker-empty", "No icons found" }
+                }
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys_in_search_box() {
+        let key = |key| Navigation::from_key(&key, 8, 6, false);
+        assert_eq!(key(Key::ArrowDown), Some(Navigation::By(8)));
+        assert_eq!(key(Key::ArrowUp), Some(Navigation::By(-8)));
+        assert_eq!(key(Key::PageDown), Some(Navigation::By(48)));
+        assert_eq!(key(Key::Enter), Some(Navigation::Select));
+        // Left, right, home, end and space edit the query
+        assert_eq!(key(Key::ArrowLeft), None);
+        assert_eq!(key(Key::Home), None);
+        assert_eq!(key(Key::Character(" ".to_string())), None);
+        assert_eq!(key(Key::Character("a".to_string())), None);
+    }
+
+    #[test]
+    fn keys_in_grid() {
+        let key = |key| Navigation::from_key(&key, 8, 6, true);
+        assert_eq!(key(Key::ArrowLeft), Some(Navigation::By(-1)));
+        assert_eq!(key(Key::ArrowRight), Some(Navigation::By(1)));
+        assert_eq!(key(Key::PageUp), Some(Navigation::By(-48)));
+        assert_eq!(key(Key::Home), Some(Navigation::First));
+        assert_eq!(key(Key::End), Some(Navigation::Last));
+        assert_eq!(
+            key(Key::Character(" ".to_string())),
+            Some(Navigation::Select)
+        );
+        assert_eq!(key(Key::Tab), None);
+    }
+
+    #[test]
+    fn clamps_to_results() {
+        assert_eq!(Navigation::By(8).target(3, 20), 11);
+        assert_eq!(Navigation::By(8).target(15, 20), 19);
+        assert_eq!(Navigation::By(-8).target(3, 20), 0);
+        assert_eq!(Navigation::By(-48).target(30, 40), 0);
+        assert_eq!(Navigation::First.target(7, 20), 0);
+        assert_eq!(Navigation::Last.target(7, 20), 19);
+        // The active cell may be out of range after the results changed
+        assert_eq!(Navigation::Select.target(30, 20), 19);
+        assert_eq!(Navigation::By(1).target(30, 20), 19);
+    }
+}
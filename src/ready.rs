@@ -0,0 +1,79 @@
+//! Loading state of the icon fonts
+
+use dioxus::prelude::*;
+
+use crate::MaterialIconStyle;
+
+/// Resolves once a face of one of the families has been loaded
+///
+/// `document.fonts.load()` resolves with no faces while the stylesheet defining them hasn't been loaded yet,
+/// so it is retried whenever the browser finishes loading fonts.
+const SCRIPT: &str = r#"
+const families = FAMILIES;
+const loaded = async () => {
+    const faces = await Promise.all(families.map((family) => document.fonts.load(`24px "${family}"`)));
+    return faces.some((faces) => faces.length > 0);
+};
+while (!(await loaded())) {
+    await new Promise((resolve) => document.fonts.addEventListener("loadingdone", resolve, { once: true }));
+}
+return true;
+"#;
+
+/// Whether the icon fonts are loaded, shared by the whole app
+#[derive(Clone, Copy)]
+struct FontsReady(Signal<bool>);
+
+/// Returns whether the icon font has finished loading
+///
+/// Uses the [`FontFace` API](https://developer.mozilla.org/en-US/docs/Web/API/CSS_Font_Loading_API) of the
+/// browser or webview, so it works on web and desktop. The font is only checked once for the whole app,
+/// starting with the first call of this hook or the first icon with
+/// [`hide_until_loaded`](crate::MaterialIconProps::hide_until_loaded).
+/// On renderers without JavaScript, the font is assumed to be loaded.
+///
+/// ```
+/// # use dioxus::prelude::*;
+/// # use dioxus_material_symbols::{use_material_symbols_ready, MaterialIcon};
+/// #[component]
+/// fn Toolbar() -> Element {
+///     let ready = use_material_symbols_ready();
+///     rsx!(
+///         if ready {
+///             MaterialIcon { name: "menu" }
+///         } else {
+///             span { "Menu" }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_material_symbols_ready() -> bool {
+    use_hook(fonts_ready)()
+}
+
+/// Returns the signal of whether the icon font has finished loading, starting the check on the first call
+///
+/// Not a hook, so components can call it only when they depend on the loading state.
+pub(crate) fn fonts_ready() -> Signal<bool> {
+    let ready = match try_consume_context::<FontsReady>() {
+        Some(ready) => ready,
+        None => {
+            let mut ready = FontsReady(Signal::new_in_scope(false, ScopeId::ROOT));
+            provide_root_context(ready);
+            spawn_forever(async move {
+                let families = [
+                    MaterialIconStyle::Outlined,
+                    MaterialIconStyle::Rounded,
+                    MaterialIconStyle::Sharp,
+                ]
+                .map(|style| style.family());
+                let script = SCRIPT.replace("FAMILIES", &format!("{families:?}"));
+                // Without JavaScript, there is no way to know, so the icons are shown right away
+                let loaded = document::eval(&script).join::<bool>().await.unwrap_or(true);
+                ready.0.set(loaded);
+            });
+            ready
+        }
+    };
+    ready.0
+}
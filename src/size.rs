@@ -0,0 +1,217 @@
+//! Icon sizes with CSS units and the Material size tokens
+
+use dioxus::prelude::SuperFrom;
+
+/// Size of an icon
+///
+/// Integers are sizes in pixels and strings are CSS lengths, so props accept `size: 24` or `size: "1.5rem"`.
+/// The [Material size tokens](https://m3.material.io/styles/icons/applying-icons) are available as constants,
+/// e.g. [`MaterialIconSize::DP_24`](MaterialIconSize::DP_24).
+///
+/// ```
+/// # use dioxus_material_symbols::MaterialIconSize;
+/// assert_eq!(MaterialIconSize::DP_20.to_css(), "20px");
+/// assert_eq!(MaterialIconSize::Em(1.5).to_css(), "1.5em");
+/// assert_eq!(MaterialIconSize::from("calc(1em + 4px)").to_css(), "calc(1em + 4px)");
+/// ```
+#[derive(PartialEq, Clone, Debug)]
+pub enum MaterialIconSize {
+    /// Pixels
+    Px(u32),
+    /// Relative to the font size of the parent
+    Em(f32),
+    /// Relative to the font size of the root element
+    Rem(f32),
+    /// Any other CSS length (e.g. `2vw` or `calc(1em + 4px)`)
+    ///
+    /// Only a number with a unit, or a `calc()`, `min()`, `max()` or `clamp()` expression is valid.
+    /// Other values are ignored, so they can't break out of the `style` attribute.
+    Length(String),
+}
+
+impl MaterialIconSize {
+    /// 18dp, for dense layouts
+    pub const DP_18: MaterialIconSize = MaterialIconSize::Px(18);
+    /// 20dp, e.g. for icons next to text
+    pub const DP_20: MaterialIconSize = MaterialIconSize::Px(20);
+    /// 24dp, the standard size
+    pub const DP_24: MaterialIconSize = MaterialIconSize::Px(24);
+    /// 40dp, e.g. for prominent icons
+    pub const DP_40: MaterialIconSize = MaterialIconSize::Px(40);
+    /// 48dp, e.g. for large touch targets
+    pub const DP_48: MaterialIconSize = MaterialIconSize::Px(48);
+
+    /// Converts the size to its CSS length
+    ///
+    /// Ignored lengths are converted to `inherit`.
+    pub fn to_css(&self) -> String {
+        match self {
+            MaterialIconSize::Px(px) => format!("{px}px"),
+            MaterialIconSize::Em(em) => format!("{em}em"),
+            MaterialIconSize::Rem(rem) => format!("{rem}rem"),
+            MaterialIconSize::Length(length) if is_length(length) => length.clone(),
+            MaterialIconSize::Length(_) => "inherit".to_string(),
+        }
+    }
+}
+
+/// Units of CSS lengths, in lowercase
+const UNITS: &[&str] = &[
+    "%", "px", "em", "rem", "ex", "rex", "ch", "rch", "cap", "rcap", "ic", "ric", "lh", "rlh",
+    "vw", "vh", "vi", "vb", "vmin", "vmax", "svw", "svh", "svi", "svb", "svmin", "svmax", "lvw",
+    "lvh", "lvi", "lvb", "lvmin", "lvmax", "dvw", "dvh", "dvi", "dvb", "dvmin", "dvmax", "cqw",
+    "cqh", "cqi", "cqb", "cqmin", "cqmax", "cm", "mm", "q", "in", "pt", "pc",
+];
+
+/// Math functions which may compute a length
+const FUNCTIONS: &[&str] = &["calc", "min", "max", "clamp"];
+
+/// Returns whether the value is a number with a unit, or a math function of lengths
+///
+/// Arguments of math functions are not checked further than containing only numbers, units, operators
+/// and nested functions (e.g. `var(--size)`) with balanced parentheses, which can't escape the declaration.
+fn is_length(value: &str) -> bool {
+    is_dimension(value) || is_math_function(value)
+}
+
+/// Returns whether the value is a number followed by a unit, e.g. `1.5rem`, or a plain `0`
+fn is_dimension(value: &str) -> bool {
+    let digits = value.strip_prefix(['+', '-']).unwrap_or(value);
+    let unit_start = digits
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(digits.len());
+    let (number, unit) = digits.split_at(unit_start);
+    let valid_number = number.parse::<f64>().is_ok_and(f64::is_finite) && !number.ends_with('.');
+    valid_number
+        && (UNITS.contains(&unit.to_ascii_lowercase().as_str())
+            || unit.is_empty() && number.parse() == Ok(0.0))
+}
+
+/// Returns whether the value is a `calc()`, `min()`, `max()` or `clamp()` expression with balanced parentheses
+fn is_math_function(value: &str) -> bool {
+    let Some((name, arguments)) = value.split_once('(') else {
+        return false;
+    };
+    if !FUNCTIONS.contains(&name.to_ascii_lowercase().as_str()) || value.contains("/*") {
+        return false;
+    }
+    let mut depth = 1usize;
+    for (i, c) in arguments.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                // The closing parenthesis of the function has to be the last character
+                if depth == 0 {
+                    return i == arguments.len() - 1;
+                }
+            }
+            c if c.is_ascii_alphanumeric() || c.is_ascii_whitespace() => {}
+            '.' | '+' | '-' | '*' | '/' | ',' | '%' => {}
+            _ => return false,
+        }
+    }
+    false
+}
+
+impl From<u32> for MaterialIconSize {
+    fn from(value: u32) -> Self {
+        Self::Px(value)
+    }
+}
+
+impl From<&str> for MaterialIconSize {
+    fn from(value: &str) -> Self {
+        let length = value.trim();
+        if !is_length(length) {
+            tracing::warn!("invalid CSS length `{length}`, using `inherit` instead");
+        }
+        Self::Length(length.to_string())
+    }
+}
+
+impl From<String> for MaterialIconSize {
+    fn from(value: String) -> Self {
+        value.as_str().into()
+    }
+}
+
+#[doc(hidden)]
+pub struct OptionSizeFromMarker;
+
+// Allows passing pixels to optional size props, e.g. `size: 24`
+impl SuperFrom<u32, OptionSizeFromMarker> for Option<MaterialIconSize> {
+    fn super_from(input: u32) -> Self {
+        Some(input.into())
+    }
+}
+
+// Allows passing CSS lengths to optional size props, e.g. `size: "1.5rem"`
+impl SuperFrom<&str, OptionSizeFromMarker> for Option<MaterialIconSize> {
+    fn super_from(input: &str) -> Self {
+        Some(input.into())
+    }
+}
+
+impl SuperFrom<String, OptionSizeFromMarker> for Option<MaterialIconSize> {
+    fn super_from(input: String) -> Self {
+        Some(input.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dimensions() {
+        for length in [
+            "1.5rem", "24px", "2VW", "-0.5em", "+3pt", ".5em", "100%", "0",
+        ] {
+            assert_eq!(MaterialIconSize::from(length).to_css(), length, "{length}");
+        }
+    }
+
+    #[test]
+    fn math_functions() {
+        for length in [
+            "calc(1em + 4px)",
+            "min(2vw, 32px)",
+            "clamp(16px, 2vw + 1rem, calc(48px - 0.5em))",
+            "calc(var(--icon-size) * 2)",
+        ] {
+            assert_eq!(MaterialIconSize::from(length).to_css(), length, "{length}");
+        }
+    }
+
+    #[test]
+    fn rejects_other_values() {
+        for length in [
+            "",
+            "24",
+            "1e",
+            "+-1em",
+            "1.em",
+            "1.5 rem",
+            "1em; color: red",
+            "1em}body{color:red",
+            "red",
+            "inf",
+            "NaNpx",
+            "url(x)",
+            "calc(1em + 4px",
+            "calc(1em + 4px))",
+            "calc(1em) + 4px",
+            "calc(1em /* x */ + 4px)",
+            "calc(1em + \"4px\")",
+            "calc(1em + 4px); color: red",
+            "calc(1em + 4px) ",
+        ] {
+            assert_eq!(
+                MaterialIconSize::Length(length.to_string()).to_css(),
+                "inherit",
+                "{length}"
+            );
+        }
+    }
+}
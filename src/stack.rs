@@ -0,0 +1,198 @@
+//! Layered icons
+
+use dioxus::prelude::*;
+
+use crate::{
+    IconTheme, MaterialIcon, MaterialIconColor, MaterialIconMode, MaterialIconSize,
+    MaterialIconStyle,
+};
+
+/// Styles of all icon stacks, included by the [`MaterialIconStylesheet`](crate::MaterialIconStylesheet)
+pub(crate) const CSS: &str = include_str!("./stack-styles.css");
+
+/// Props for the [`MaterialIconStack`](MaterialIconStack) component
+#[derive(Props, Clone, PartialEq)]
+pub struct MaterialIconStackProps {
+    /// Size of the stack
+    ///
+    /// The layers are scaled relative to it. Inherits the font size of the parent if not set.
+    ///
+    /// Optional
+    #[props(into)]
+    pub size: Option<MaterialIconSize>,
+    /// Accessible label of the whole stack (e.g. `Syncing`)
+    ///
+    /// Stacks without a label are decorative and hidden from assistive technology.
+    ///
+    /// Optional
+    #[props(into)]
+    pub label: Option<String>,
+    /// Tooltip shown on hover
+    ///
+    /// Optional
+    #[props(into)]
+    pub title: Option<String>,
+    /// Additional CSS classes
+    ///
+    /// Optional
+    #[props(into)]
+    pub class: Option<String>,
+    /// Additional inline styles
+    ///
+    /// Optional
+    #[props(into)]
+    pub style: Option<String>,
+    /// Any other attributes, passed through to the stack element
+    #[props(extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+    /// Layers of the stack, from bottom to top
+    ///
+    /// Usually [`MaterialIconLayer`](MaterialIconLayer)s.
+    pub children: Element,
+}
+
+/// Material Icon Stack component
+///
+/// Layers several symbols on top of each other, e.g. a small `sync` over a `cloud`
+/// or a glyph on a `circle` background. The stack takes the space of a single icon
+/// and each [`MaterialIconLayer`](MaterialIconLayer) is centered in it.
+///
+/// Layers are sized relative to the stack, so the `size` of a [`MaterialIconTheme`](crate::MaterialIconTheme)
+/// only applies to the stack itself.
+///
+/// ```
+/// # use dioxus::prelude::*;
+/// # use dioxus_material_symbols::{MaterialIconLayer, MaterialIconStack};
+/// #[component]
+/// fn SyncStatus() -> Element {
+///     rsx!(
+///         MaterialIconStack { size: 24, label: "Syncing",
+///             MaterialIconLayer { name: "cloud" }
+///             MaterialIconLayer {
+///                 name: "sync",
+///                 scale: 0.5,
+///                 offset_x: 0.25,
+///                 offset_y: 0.25,
+///                 color: "white",
+///             }
+///         }
+///     )
+/// }
+/// ```
+#[component]
+pub fn MaterialIconStack(props: MaterialIconStackProps) -> Element {
+    // Read before providing the layer theme, which would shadow it on later renders
+    let parent = use_hook(try_consume_context::<Memo<IconTheme>>);
+    // The layers keep the rest of the theme, but take their size from the stack
+    let layer_theme = use_memo(move || IconTheme {
+        size: None,
+        ..parent.map(|theme| theme()).unwrap_or_default()
+    });
+    use_context_provider(|| layer_theme);
+
+    let role = props.label.is_some().then_some("img");
+    let aria_hidden = props.label.is_none().then_some("true");
+    let css_size = props
+        .size
+        .or_else(|| parent.and_then(|theme| theme.read().size.clone()))
+        .map(|s| s.to_css())
+        .unwrap_or_else(|| "inherit".to_string());
+    let class = match &props.class {
+        Some(extra) => format!("material-symbols-stack {extra}"),
+        None => "material-symbols-stack".to_string(),
+    };
+    let style = match &props.style {
+        Some(extra) => format!("font-size: {css_size}; {extra}"),
+        None => format!("font-size: {css_size};"),
+    };
+    rsx!(
+        span {
+            class,
+            style,
+            role,
+            aria_label: props.label.clone(),
+            aria_hidden,
+            title: props.title.clone(),
+            ..props.attributes,
+            {props.children}
+        }
+    )
+}
+
+/// Props for the [`MaterialIconLayer`](MaterialIconLayer) component
+#[derive(Props, Clone, PartialEq)]
+pub struct MaterialIconLayerProps {
+    /// Name of the symbol (e.g. `home` or [`symbols::HOME`](crate::symbols::HOME))
+    #[props(into)]
+    pub name: String,
+    /// Size of the layer relative to the stack (e.g. `0.5` for half the size)
+    #[props(default = 1.0)]
+    pub scale: f32,
+    /// Horizontal offset from the center, relative to the size of the stack (e.g. `0.25` moves it a quarter to the right)
+    #[props(default)]
+    pub offset_x: f32,
+    /// Vertical offset from the center, relative to the size of the stack (e.g. `0.25` moves it a quarter down)
+    #[props(default)]
+    pub offset_y: f32,
+    /// Color of the layer
+    ///
+    /// See [`MaterialIconProps::color`](crate::MaterialIconProps::color).
+    ///
+    /// Optional
+    #[props(into)]
+    pub color: Option<MaterialIconColor>,
+    /// Fill of the layer
+    ///
+    /// See [`MaterialIconProps::fill`](crate::MaterialIconProps::fill).
+    ///
+    /// Optional
+    pub fill: Option<bool>,
+    /// Weight of the layer
+    ///
+    /// See [`MaterialIconProps::weight`](crate::MaterialIconProps::weight).
+    ///
+    /// Optional
+    pub weight: Option<u16>,
+    /// Style of the symbol
+    ///
+    /// See [`MaterialIconProps::variant`](crate::MaterialIconProps::variant).
+    ///
+    /// Optional
+    pub variant: Option<MaterialIconStyle>,
+    /// Rendering mode of the symbol
+    ///
+    /// See [`MaterialIconMode`](MaterialIconMode) for more information.
+    #[props(default)]
+    pub mode: MaterialIconMode,
+    /// Additional CSS classes
+    ///
+    /// Optional
+    #[props(into)]
+    pub class: Option<String>,
+}
+
+/// Material Icon Layer component
+///
+/// A single symbol in a [`MaterialIconStack`](MaterialIconStack), centered in the stack
+/// before it is scaled and offset.
+#[component]
+pub fn MaterialIconLayer(props: MaterialIconLayerProps) -> Element {
+    let scale = props.scale;
+    let x = props.offset_x * 100.0;
+    let y = props.offset_y * 100.0;
+    rsx!(
+        span {
+            class: "material-symbols-stack-layer",
+            style: "font-size: {scale}em; transform: translate({x}%, {y}%);",
+            MaterialIcon {
+                name: props.name,
+                color: props.color,
+                fill: props.fill,
+                weight: props.weight,
+                variant: props.variant,
+                mode: props.mode,
+                class: props.class,
+            }
+        }
+    )
+}
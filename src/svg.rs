@@ -0,0 +1,20 @@
+//! Inline SVG path data
+//!
+//! The tables are generated by the build script from the glyph outlines of the font of each style,
+//! sorted by name and scaled to a `0 0 24 24` view box.
+
+#[cfg(feature = "svg-outlined")]
+pub(crate) static OUTLINED: &[(&str, &str)] =
+    include!(concat!(env!("OUT_DIR"), "/svg_outlined.rs"));
+#[cfg(feature = "svg-rounded")]
+pub(crate) static ROUNDED: &[(&str, &str)] = include!(concat!(env!("OUT_DIR"), "/svg_rounded.rs"));
+#[cfg(feature = "svg-sharp")]
+pub(crate) static SHARP: &[(&str, &str)] = include!(concat!(env!("OUT_DIR"), "/svg_sharp.rs"));
+
+/// Looks up the path data of a symbol in one of the tables
+pub(crate) fn path(table: &'static [(&str, &str)], name: &str) -> Option<&'static str> {
+    table
+        .binary_search_by_key(&name, |(n, _)| n)
+        .ok()
+        .map(|i| table[i].1)
+}
@@ -0,0 +1,191 @@
+//! Compile-time checked symbol names
+//!
+//! This module contains a constant for every symbol in the bundled codepoints file,
+//! so a misspelled symbol fails to compile instead of rendering its name as text.
+//!
+//! The bundled file is currently the one of the classic Material Icons font (`data/codepoints`).
+//! Symbols which only exist in Material Symbols (e.g. `progress_activity`) have no constant yet,
+//! but can still be rendered by passing their name as a string.
+//!
+//! Constant names are the uppercase symbol names, e.g. [`HOME`] for `home`.
+//! Names starting with a digit are prefixed with an underscore, e.g. [`_3D_ROTATION`] for `3d_rotation`.
+//!
+//! The codepoints of all symbols are available through [`codepoint`].
+//! The symbols which are mirrored in right-to-left layouts are listed in [`DIRECTIONAL`].
+
+use std::fmt;
+use std::ops::Deref;
+
+/// A symbol name which is listed in the bundled codepoints file
+///
+/// Use the constants in the [`symbols`](crate::symbols) module to get a `Symbol`.
+/// It dereferences to `str` and converts into `String`, so it can be passed anywhere a symbol name is expected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Symbol(&'static str);
+
+impl Symbol {
+    /// Name of the symbol (e.g. `home`)
+    pub const fn name(&self) -> &'static str {
+        self.0
+    }
+
+    /// Codepoint of the symbol in the Private Use Area of the font (e.g. `U+E88A` for `home`)
+    pub fn codepoint(&self) -> char {
+        codepoint(self.0).expect("every symbol has a codepoint")
+    }
+
+    /// Whether the symbol points in the reading direction, see [`DIRECTIONAL`]
+    pub fn is_directional(&self) -> bool {
+        is_directional(self.0)
+    }
+}
+
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl From<&Symbol> for String {
+    fn from(value: &Symbol) -> Self {
+        value.0.to_string()
+    }
+}
+
+impl Deref for Symbol {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.0
+    }
+}
+
+/// Codepoints of all symbols, sorted by name
+static CODEPOINTS: &[(&str, char)] = include!(concat!(env!("OUT_DIR"), "/codepoints.rs"));
+
+/// Returns the codepoint of a symbol in the Private Use Area of the font
+///
+/// The codepoints are the ones of the bundled codepoints file, which match the classic Material Icons font.
+/// They are not guaranteed to match the Material Symbols fonts, so [`MaterialIconMode::Codepoint`](crate::MaterialIconMode::Codepoint)
+/// may render a different glyph with those fonts.
+///
+/// ```
+/// # use dioxus_material_symbols::codepoint;
+/// assert_eq!(codepoint("home"), Some('\u{e88a}'));
+/// assert_eq!(codepoint("not_a_symbol"), None);
+/// ```
+pub fn codepoint(name: &str) -> Option<char> {
+    CODEPOINTS
+        .binary_search_by(|(n, _)| (*n).cmp(name))
+        .ok()
+        .map(|i| CODEPOINTS[i].1)
+}
+
+/// Symbols which point in the reading direction, sorted by name
+///
+/// Following the [Material bidirectionality guidelines](https://m3.material.io/foundations/layout/understanding-layout/bidirectionality-rtl),
+/// these symbols (e.g. [`ARROW_FORWARD`], [`SEND`] or [`REPLY`]) are mirrored in right-to-left layouts,
+/// while all other symbols keep their orientation.
+pub static DIRECTIONAL: &[&Symbol] = include!(concat!(env!("OUT_DIR"), "/directional.rs"));
+
+/// Returns whether a symbol points in the reading direction, see [`DIRECTIONAL`]
+///
+/// ```
+/// # use dioxus_material_symbols::is_directional;
+/// assert!(is_directional("arrow_forward"));
+/// assert!(!is_directional("home"));
+/// ```
+pub fn is_directional(name: &str) -> bool {
+    DIRECTIONAL
+        .binary_search_by(|symbol| symbol.name().cmp(name))
+        .is_ok()
+}
+
+include!(concat!(env!("OUT_DIR"), "/symbols.rs"));
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Write;
+
+    use ttf_parser::gsub::SubstitutionSubtable;
+    use ttf_parser::{Face, GlyphId, OutlineBuilder};
+
+    use super::*;
+
+    /// The font bundled with this crate, which the codepoints are taken from
+    const FONT: &[u8] = include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/data/fonts/MaterialIcons-Regular.ttf"
+    ));
+
+    /// Finds the glyph the font substitutes for the ligature `name`
+    fn ligature(face: &Face, name: &str) -> Option<GlyphId> {
+        let glyphs: Vec<GlyphId> = name
+            .chars()
+            .map(|c| face.glyph_index(c))
+            .collect::<Option<_>>()?;
+        let (first, rest) = glyphs.split_first()?;
+        face.tables()
+            .gsub?
+            .lookups
+            .into_iter()
+            .flat_map(|lookup| lookup.subtables.into_iter::<SubstitutionSubtable>())
+            .filter_map(|subtable| match subtable {
+                SubstitutionSubtable::Ligature(ligatures) => Some(ligatures),
+                _ => None,
+            })
+            .filter_map(|ligatures| {
+                let i = ligatures.coverage.get(*first)?;
+                ligatures.ligature_sets.get(i)
+            })
+            .flat_map(|set| set.into_iter())
+            .find(|l| l.components.into_iter().eq(rest.iter().copied()))
+            .map(|l| l.glyph)
+    }
+
+    /// Records the outline of a glyph, to compare glyphs which the font stores more than once
+    #[derive(Default)]
+    struct Outline(String);
+
+    impl OutlineBuilder for Outline {
+        fn move_to(&mut self, x: f32, y: f32) {
+            write!(self.0, "M{x} {y}").unwrap();
+        }
+
+        fn line_to(&mut self, x: f32, y: f32) {
+            write!(self.0, "L{x} {y}").unwrap();
+        }
+
+        fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+            write!(self.0, "Q{x1} {y1} {x} {y}").unwrap();
+        }
+
+        fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+            write!(self.0, "C{x1} {y1} {x2} {y2} {x} {y}").unwrap();
+        }
+
+        fn close(&mut self) {
+            self.0.push('Z');
+        }
+    }
+
+    fn outline(face: &Face, glyph: GlyphId) -> String {
+        let mut outline = Outline::default();
+        face.outline_glyph(glyph, &mut outline);
+        outline.0
+    }
+
+    #[test]
+    fn codepoints_match_ligatures() {
+        let face = Face::parse(FONT, 0).unwrap();
+        for (name, codepoint) in CODEPOINTS {
+            let by_codepoint = face.glyph_index(*codepoint).expect(name);
+            let by_name = ligature(&face, name).expect(name);
+            assert_eq!(
+                outline(&face, by_codepoint),
+                outline(&face, by_name),
+                "{name}"
+            );
+        }
+    }
+}
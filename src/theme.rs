@@ -0,0 +1,131 @@
+//! App-wide defaults for icons, provided through the Dioxus context
+
+use dioxus::prelude::*;
+
+use crate::{MaterialIconColor, MaterialIconSize, MaterialIconStyle};
+
+/// Default values for all icons in a subtree
+///
+/// Provided by the [`MaterialIconTheme`](MaterialIconTheme) component and read with [`use_icon_theme`].
+/// `None` means that the value is not set by the theme.
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct IconTheme {
+    /// Default style, see [`MaterialIconProps::variant`](crate::MaterialIconProps::variant)
+    pub variant: Option<MaterialIconStyle>,
+    /// Default size
+    pub size: Option<MaterialIconSize>,
+    /// Default color
+    pub color: Option<MaterialIconColor>,
+    /// Default value of the `FILL` axis
+    pub fill: Option<bool>,
+    /// Default value of the `wght` axis
+    pub weight: Option<u16>,
+    /// Default value of the `GRAD` axis
+    pub grade: Option<i16>,
+    /// Default value of the `opsz` axis
+    pub optical_size: Option<u16>,
+}
+
+impl IconTheme {
+    /// Returns this theme with the values it doesn't set taken from `parent`
+    fn or(self, parent: &IconTheme) -> IconTheme {
+        IconTheme {
+            variant: self.variant.or(parent.variant),
+            size: self.size.or_else(|| parent.size.clone()),
+            color: self.color.or_else(|| parent.color.clone()),
+            fill: self.fill.or(parent.fill),
+            weight: self.weight.or(parent.weight),
+            grade: self.grade.or(parent.grade),
+            optical_size: self.optical_size.or(parent.optical_size),
+        }
+    }
+}
+
+/// Props for the [`MaterialIconTheme`](MaterialIconTheme) component
+#[derive(Props, Clone, PartialEq)]
+pub struct MaterialIconThemeProps {
+    /// Default style
+    ///
+    /// Optional
+    pub variant: Option<MaterialIconStyle>,
+    /// Default size
+    ///
+    /// Optional
+    #[props(into)]
+    pub size: Option<MaterialIconSize>,
+    /// Default color
+    ///
+    /// Optional
+    #[props(into)]
+    pub color: Option<MaterialIconColor>,
+    /// Default value of the `FILL` axis
+    ///
+    /// Optional
+    pub fill: Option<bool>,
+    /// Default value of the `wght` axis
+    ///
+    /// Optional
+    pub weight: Option<u16>,
+    /// Default value of the `GRAD` axis
+    ///
+    /// Optional
+    pub grade: Option<i16>,
+    /// Default value of the `opsz` axis
+    ///
+    /// Optional
+    pub optical_size: Option<u16>,
+    /// Subtree the theme applies to
+    pub children: Element,
+}
+
+/// Material Icon Theme component
+///
+/// Provides default values to all [`MaterialIcon`](crate::MaterialIcon)s in its children,
+/// which are used when the props of an icon are `None`.
+///
+/// Themes can be nested. Values that a nested theme doesn't set are inherited from the outer theme:
+///
+/// ```
+/// # use dioxus::prelude::*;
+/// # use dioxus_material_symbols::{MaterialIcon, MaterialIconTheme};
+/// #[component]
+/// fn App() -> Element {
+///     rsx!(
+///         MaterialIconTheme { size: 24, color: "#1d1b20",
+///             MaterialIcon { name: "home" }
+///             // Toolbar icons are smaller, but keep the color of the app
+///             MaterialIconTheme { size: 20,
+///                 MaterialIcon { name: "settings" }
+///             }
+///         }
+///     )
+/// }
+/// ```
+#[component]
+pub fn MaterialIconTheme(props: MaterialIconThemeProps) -> Element {
+    let parent = try_use_context::<Memo<IconTheme>>();
+    let own = IconTheme {
+        variant: props.variant,
+        size: props.size,
+        color: props.color,
+        fill: props.fill,
+        weight: props.weight,
+        grade: props.grade,
+        optical_size: props.optical_size,
+    };
+    let theme = use_memo(use_reactive!(|own| match parent {
+        Some(parent) => own.or(&parent.read()),
+        None => own,
+    }));
+    use_context_provider(|| theme);
+    rsx!({ props.children })
+}
+
+/// Returns the icon theme of the closest [`MaterialIconTheme`](MaterialIconTheme)
+///
+/// Returns the default, which sets no values, outside of a theme.
+pub fn use_icon_theme() -> IconTheme {
+    try_use_context::<Memo<IconTheme>>()
+        .map(|theme| theme())
+        .unwrap_or_default()
+}
@@ -0,0 +1,108 @@
+//! # Material Symbols Subset
+//!
+//! Command line interface of [`dioxus_material_symbols_subset`].
+//!
+//! ```text
+//! material-symbols-subset <FONT> -o <OUTPUT> [--names home,settings] [--scan src]
+//! ```
+
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::{env, fs};
+
+use dioxus_material_symbols_subset::{known_symbols, scan_dir, subset};
+
+const USAGE: &str = "\
+Usage: material-symbols-subset <FONT> -o <OUTPUT> [OPTIONS]
+
+Writes a copy of FONT which only contains the given symbols.
+
+Options:
+  -o, --output <OUTPUT>  Path of the subset font
+  -n, --names <NAMES>    Comma separated symbol names to keep
+  -s, --scan <DIR>       Keep the symbols used in the Rust sources in DIR
+  -h, --help             Print this help";
+
+struct Args {
+    font: PathBuf,
+    output: PathBuf,
+    names: BTreeSet<String>,
+    scan: Vec<PathBuf>,
+}
+
+/// Parses the command line arguments, or returns `None` if the help was requested
+fn parse_args() -> Result<Option<Args>, String> {
+    let mut font = None;
+    let mut output = None;
+    let mut names = BTreeSet::new();
+    let mut scan = Vec::new();
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("missing value for `{arg}`"));
+        match arg.as_str() {
+            "-o" | "--output" => output = Some(PathBuf::from(value()?)),
+            "-n" | "--names" => names.extend(
+                value()?
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|n| !n.is_empty())
+                    .map(String::from),
+            ),
+            "-s" | "--scan" => scan.push(PathBuf::from(value()?)),
+            "-h" | "--help" => return Ok(None),
+            _ if font.is_none() && !arg.starts_with('-') => font = Some(PathBuf::from(arg)),
+            _ => return Err(format!("unexpected argument `{arg}`\n\n{USAGE}")),
+        }
+    }
+
+    Ok(Some(Args {
+        font: font.ok_or(USAGE)?,
+        output: output.ok_or(USAGE)?,
+        names,
+        scan,
+    }))
+}
+
+fn run(args: Args) -> Result<(), Box<dyn std::error::Error>> {
+    let font = fs::read(&args.font)?;
+
+    let mut scanned = BTreeSet::new();
+    for dir in &args.scan {
+        scanned.append(&mut scan_dir(dir)?);
+    }
+    let mut names = args.names;
+    names.extend(known_symbols(&font, scanned)?);
+
+    let subset = subset(&font, &names)?;
+    fs::write(&args.output, &subset)?;
+    eprintln!(
+        "Kept {} symbols, {} -> {} bytes",
+        names.len(),
+        font.len(),
+        subset.len()
+    );
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args() {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::FAILURE;
+        }
+    };
+    match run(args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,442 @@
+//! Rewrites the tables of a TrueType font, keeping only the outlines of the retained glyphs
+//!
+//! Glyph ids are never renumbered. Removed glyphs become empty, so tables referring to
+//! glyph ids (`cmap`, `GSUB`, `hmtx`, ...) are copied unchanged.
+//! Only `glyf`, `loca` and `gvar` are rebuilt, which hold almost all of the file size.
+
+use std::borrow::Cow;
+use std::collections::BTreeSet;
+
+use ttf_parser::gsub::SubstitutionSubtable;
+use ttf_parser::{Face, GlyphId};
+
+use crate::Error;
+
+/// Subsets a font to the given symbol names
+///
+/// Keeps the glyphs of the ligatures of `names`, their components and the glyphs of
+/// printable ASCII characters, which the ligatures are typed with.
+/// Fails if one of the names is not a ligature in the font.
+pub fn subset<I, S>(font: &[u8], names: I) -> Result<Vec<u8>, Error>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let face = Face::parse(font, 0)?;
+    let tables = Tables::parse(font)?;
+
+    let mut retained = BTreeSet::from([0]);
+    for c in (0x20u8..0x7f).map(char::from) {
+        if let Some(glyph) = face.glyph_index(c) {
+            retained.insert(glyph.0);
+        }
+    }
+    for name in names {
+        let name = name.as_ref();
+        let glyph = ligature(&face, name).ok_or_else(|| Error::UnknownSymbol(name.to_string()))?;
+        retained.insert(glyph.0);
+    }
+
+    let head = tables.get("head")?;
+    if head.len() < 54 {
+        return Err(Error::MalformedTable("head"));
+    }
+    let glyphs = Glyphs::parse(&tables, head, face.number_of_glyphs())?;
+    glyphs.add_components(&mut retained)?;
+
+    let (glyf, loca) = glyphs.subset(&retained);
+    let mut head = head.to_vec();
+    // Checksum adjustment, fixed after writing the font
+    head[8..12].fill(0);
+    // Always write long `loca` offsets
+    head[50..52].copy_from_slice(&1i16.to_be_bytes());
+
+    let mut out: Vec<([u8; 4], Cow<[u8]>)> = Vec::new();
+    for (tag, data) in &tables.records {
+        let data = match tag {
+            b"glyf" => Cow::Owned(glyf.clone()),
+            b"loca" => Cow::Owned(loca.clone()),
+            b"head" => Cow::Owned(head.clone()),
+            b"gvar" => Cow::Owned(subset_gvar(data, &retained)?),
+            // Signatures are invalid after any modification
+            b"DSIG" => continue,
+            _ => Cow::Borrowed(*data),
+        };
+        out.push((*tag, data));
+    }
+    Ok(write_font(tables.version, out))
+}
+
+/// Filters symbol names to the ones which are ligatures in the font
+///
+/// Useful to clean up the results of [`scan_dir`](crate::scan_dir) before passing them to [`subset`].
+pub fn known_symbols<I, S>(font: &[u8], names: I) -> Result<Vec<S>, Error>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let face = Face::parse(font, 0)?;
+    Ok(names
+        .into_iter()
+        .filter(|name| ligature(&face, name.as_ref()).is_some())
+        .collect())
+}
+
+/// Finds the glyph the font substitutes for the ligature `name`
+fn ligature(face: &Face, name: &str) -> Option<GlyphId> {
+    let glyphs: Vec<GlyphId> = name
+        .chars()
+        .map(|c| face.glyph_index(c))
+        .collect::<Option<_>>()?;
+    let (first, rest) = glyphs.split_first()?;
+    for lookup in face.tables().gsub?.lookups {
+        for subtable in lookup.subtables.into_iter::<SubstitutionSubtable>() {
+            let SubstitutionSubtable::Ligature(ligatures) = subtable else {
+                continue;
+            };
+            let Some(set) = ligatures
+                .coverage
+                .get(*first)
+                .and_then(|i| ligatures.ligature_sets.get(i))
+            else {
+                continue;
+            };
+            if let Some(ligature) = set
+                .into_iter()
+                .find(|l| l.components.into_iter().eq(rest.iter().copied()))
+            {
+                return Some(ligature.glyph);
+            }
+        }
+    }
+    None
+}
+
+/// Table directory of a font file
+struct Tables<'a> {
+    version: u32,
+    records: Vec<([u8; 4], &'a [u8])>,
+}
+
+impl<'a> Tables<'a> {
+    fn parse(font: &'a [u8]) -> Result<Self, Error> {
+        let malformed = || Error::MalformedTable("sfnt");
+        let version = read_u32(font, 0).ok_or_else(malformed)?;
+        match &version.to_be_bytes() {
+            b"OTTO" => return Err(Error::UnsupportedOutlines),
+            [0, 1, 0, 0] | b"true" => {}
+            _ => return Err(malformed()),
+        }
+        let count = read_u16(font, 4).ok_or_else(malformed)?;
+        let records = (0..usize::from(count))
+            .map(|i| {
+                let record = font.get(12 + i * 16..28 + i * 16)?;
+                let offset = read_u32(record, 8)? as usize;
+                let length = read_u32(record, 12)? as usize;
+                let tag = record[..4].try_into().ok()?;
+                Some((tag, font.get(offset..offset.checked_add(length)?)?))
+            })
+            .collect::<Option<_>>()
+            .ok_or_else(malformed)?;
+        let tables = Self { version, records };
+        if tables.get("glyf").is_err() {
+            return Err(Error::UnsupportedOutlines);
+        }
+        Ok(tables)
+    }
+
+    fn get(&self, tag: &'static str) -> Result<&'a [u8], Error> {
+        self.records
+            .iter()
+            .find(|(t, _)| t == tag.as_bytes())
+            .map(|(_, data)| *data)
+            .ok_or(Error::MalformedTable(tag))
+    }
+}
+
+/// Outlines of all glyphs, as located by the `loca` table
+struct Glyphs<'a> {
+    data: Vec<&'a [u8]>,
+}
+
+impl<'a> Glyphs<'a> {
+    fn parse(tables: &Tables<'a>, head: &[u8], count: u16) -> Result<Self, Error> {
+        let glyf = tables.get("glyf")?;
+        let loca = tables.get("loca")?;
+        let long = read_u16(head, 50) == Some(1);
+        let offset = |i: usize| {
+            if long {
+                read_u32(loca, i * 4).map(|o| o as usize)
+            } else {
+                read_u16(loca, i * 2).map(|o| usize::from(o) * 2)
+            }
+        };
+        let data = (0..usize::from(count))
+            .map(|i| glyf.get(offset(i)?..offset(i + 1)?))
+            .collect::<Option<_>>()
+            .ok_or(Error::MalformedTable("loca"))?;
+        Ok(Self { data })
+    }
+
+    /// Adds the components of all retained composite glyphs, recursively
+    fn add_components(&self, retained: &mut BTreeSet<u16>) -> Result<(), Error> {
+        let mut pending: Vec<u16> = retained.iter().copied().collect();
+        while let Some(glyph) = pending.pop() {
+            let data = self
+                .data
+                .get(usize::from(glyph))
+                .ok_or(Error::MalformedTable("glyf"))?;
+            for component in components(data).ok_or(Error::MalformedTable("glyf"))? {
+                if retained.insert(component) {
+                    pending.push(component);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Builds the `glyf` and long `loca` tables with empty outlines for all other glyphs
+    fn subset(&self, retained: &BTreeSet<u16>) -> (Vec<u8>, Vec<u8>) {
+        let mut glyf = Vec::new();
+        let mut loca = Vec::with_capacity((self.data.len() + 1) * 4);
+        for (glyph, data) in self.data.iter().enumerate() {
+            loca.extend_from_slice(&(glyf.len() as u32).to_be_bytes());
+            if retained.contains(&(glyph as u16)) {
+                glyf.extend_from_slice(data);
+                pad(&mut glyf);
+            }
+        }
+        loca.extend_from_slice(&(glyf.len() as u32).to_be_bytes());
+        (glyf, loca)
+    }
+}
+
+/// Returns the component glyph ids of a composite glyph, or none for a simple glyph
+fn components(glyph: &[u8]) -> Option<Vec<u16>> {
+    const ARG_1_AND_2_ARE_WORDS: u16 = 0x0001;
+    const WE_HAVE_A_SCALE: u16 = 0x0008;
+    const MORE_COMPONENTS: u16 = 0x0020;
+    const WE_HAVE_AN_X_AND_Y_SCALE: u16 = 0x0040;
+    const WE_HAVE_A_TWO_BY_TWO: u16 = 0x0080;
+
+    let mut components = Vec::new();
+    if glyph.is_empty() || read_u16(glyph, 0)? as i16 >= 0 {
+        return Some(components);
+    }
+    let mut offset = 10;
+    loop {
+        let flags = read_u16(glyph, offset)?;
+        components.push(read_u16(glyph, offset + 2)?);
+        offset += 4;
+        offset += if flags & ARG_1_AND_2_ARE_WORDS != 0 {
+            4
+        } else {
+            2
+        };
+        if flags & WE_HAVE_A_SCALE != 0 {
+            offset += 2;
+        } else if flags & WE_HAVE_AN_X_AND_Y_SCALE != 0 {
+            offset += 4;
+        } else if flags & WE_HAVE_A_TWO_BY_TWO != 0 {
+            offset += 8;
+        }
+        if flags & MORE_COMPONENTS == 0 {
+            return Some(components);
+        }
+    }
+}
+
+/// Rebuilds the `gvar` table of a variable font with long offsets,
+/// dropping the variation data of all other glyphs
+fn subset_gvar(gvar: &[u8], retained: &BTreeSet<u16>) -> Result<Vec<u8>, Error> {
+    const HEADER_LEN: usize = 20;
+    let malformed = || Error::MalformedTable("gvar");
+
+    let axis_count = usize::from(read_u16(gvar, 4).ok_or_else(malformed)?);
+    let shared_tuple_count = usize::from(read_u16(gvar, 6).ok_or_else(malformed)?);
+    let shared_tuples_offset = read_u32(gvar, 8).ok_or_else(malformed)? as usize;
+    let glyph_count = usize::from(read_u16(gvar, 12).ok_or_else(malformed)?);
+    let flags = read_u16(gvar, 14).ok_or_else(malformed)?;
+    let data_offset = read_u32(gvar, 16).ok_or_else(malformed)? as usize;
+
+    let long = flags & 1 != 0;
+    let offset = |i: usize| {
+        if long {
+            read_u32(gvar, HEADER_LEN + i * 4).map(|o| o as usize)
+        } else {
+            read_u16(gvar, HEADER_LEN + i * 2).map(|o| usize::from(o) * 2)
+        }
+    };
+    let shared_tuples = gvar
+        .get(shared_tuples_offset..shared_tuples_offset + shared_tuple_count * axis_count * 2)
+        .ok_or_else(malformed)?;
+
+    let mut data = Vec::new();
+    let mut offsets = Vec::with_capacity((glyph_count + 1) * 4);
+    for glyph in 0..glyph_count {
+        offsets.extend_from_slice(&(data.len() as u32).to_be_bytes());
+        if retained.contains(&(glyph as u16)) {
+            let start = data_offset + offset(glyph).ok_or_else(malformed)?;
+            let end = data_offset + offset(glyph + 1).ok_or_else(malformed)?;
+            data.extend_from_slice(gvar.get(start..end).ok_or_else(malformed)?);
+            pad(&mut data);
+        }
+    }
+    offsets.extend_from_slice(&(data.len() as u32).to_be_bytes());
+
+    let new_shared_tuples_offset = HEADER_LEN + offsets.len();
+    let new_data_offset = new_shared_tuples_offset + shared_tuples.len();
+    let mut out = Vec::with_capacity(new_data_offset + data.len());
+    out.extend_from_slice(&gvar[..8]);
+    out.extend_from_slice(&(new_shared_tuples_offset as u32).to_be_bytes());
+    out.extend_from_slice(&(glyph_count as u16).to_be_bytes());
+    out.extend_from_slice(&(flags | 1).to_be_bytes());
+    out.extend_from_slice(&(new_data_offset as u32).to_be_bytes());
+    out.extend_from_slice(&offsets);
+    out.extend_from_slice(shared_tuples);
+    out.extend_from_slice(&data);
+    Ok(out)
+}
+
+/// Writes the font file with a fresh table directory, checksums and checksum adjustment
+fn write_font(version: u32, mut tables: Vec<([u8; 4], Cow<[u8]>)>) -> Vec<u8> {
+    tables.sort_by_key(|(tag, _)| *tag);
+    let count = tables.len() as u16;
+    let entry_selector = 15 - count.leading_zeros() as u16;
+    let search_range: u16 = (1 << entry_selector) * 16;
+
+    let mut out = Vec::new();
+    out.extend_from_slice(&version.to_be_bytes());
+    out.extend_from_slice(&count.to_be_bytes());
+    out.extend_from_slice(&search_range.to_be_bytes());
+    out.extend_from_slice(&entry_selector.to_be_bytes());
+    out.extend_from_slice(&(count * 16 - search_range).to_be_bytes());
+
+    let mut offset = 12 + tables.len() * 16;
+    let mut head_offset = None;
+    for (tag, data) in &tables {
+        out.extend_from_slice(tag);
+        out.extend_from_slice(&checksum(data).to_be_bytes());
+        out.extend_from_slice(&(offset as u32).to_be_bytes());
+        out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+        if tag == b"head" {
+            head_offset = Some(offset);
+        }
+        offset += data.len().next_multiple_of(4);
+    }
+    for (_, data) in &tables {
+        out.extend_from_slice(data);
+        pad(&mut out);
+    }
+
+    if let Some(head) = head_offset {
+        let adjustment = 0xB1B0_AFBAu32.wrapping_sub(checksum(&out));
+        out[head + 8..head + 12].copy_from_slice(&adjustment.to_be_bytes());
+    }
+    out
+}
+
+fn checksum(data: &[u8]) -> u32 {
+    data.chunks(4).fold(0u32, |sum, chunk| {
+        let mut word = [0; 4];
+        word[..chunk.len()].copy_from_slice(chunk);
+        sum.wrapping_add(u32::from_be_bytes(word))
+    })
+}
+
+/// Pads to a multiple of 4 bytes, as required between tables and recommended between glyphs
+fn pad(data: &mut Vec<u8>) {
+    data.resize(data.len().next_multiple_of(4), 0);
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(
+        data.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(
+        data.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use ttf_parser::OutlineBuilder;
+
+    use super::*;
+
+    const FONT: &[u8] = include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../data/fonts/MaterialIcons-Regular.ttf"
+    ));
+
+    /// Ignores the outline, only checks that there is one
+    struct Outline;
+
+    impl OutlineBuilder for Outline {
+        fn move_to(&mut self, _: f32, _: f32) {}
+        fn line_to(&mut self, _: f32, _: f32) {}
+        fn quad_to(&mut self, _: f32, _: f32, _: f32, _: f32) {}
+        fn curve_to(&mut self, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32) {}
+        fn close(&mut self) {}
+    }
+
+    fn has_outline(face: &Face, name: &str) -> bool {
+        let glyph = ligature(face, name).expect("ligatures are kept");
+        face.outline_glyph(glyph, &mut Outline).is_some()
+    }
+
+    #[test]
+    fn keeps_only_the_retained_outlines() {
+        let font = subset(FONT, ["home"]).unwrap();
+        assert!(font.len() < FONT.len() / 2);
+        let face = Face::parse(&font, 0).unwrap();
+        assert_eq!(
+            face.number_of_glyphs(),
+            Face::parse(FONT, 0).unwrap().number_of_glyphs()
+        );
+        assert!(has_outline(&face, "home"));
+        assert!(!has_outline(&face, "settings"));
+        // Glyphs of the characters the ligatures are typed with are kept
+        let a = face.glyph_index('a').unwrap();
+        assert_eq!(
+            face.outline_glyph(a, &mut Outline).is_some(),
+            Face::parse(FONT, 0)
+                .unwrap()
+                .outline_glyph(a, &mut Outline)
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn writes_valid_checksums() {
+        let font = subset(FONT, ["home", "settings"]).unwrap();
+        assert_eq!(checksum(&font), 0xB1B0_AFBA);
+        let count = usize::from(read_u16(&font, 4).unwrap());
+        for record in font[12..12 + count * 16].chunks(16) {
+            let tag = &record[..4];
+            let offset = read_u32(record, 8).unwrap() as usize;
+            let length = read_u32(record, 12).unwrap() as usize;
+            let mut table = font[offset..offset + length].to_vec();
+            if tag == b"head" {
+                // The checksum of `head` is calculated with a zero checksum adjustment
+                table[8..12].fill(0);
+            }
+            assert_eq!(checksum(&table), read_u32(record, 4).unwrap(), "{tag:?}");
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_symbols() {
+        let error = subset(FONT, ["home", "not_a_symbol"]).unwrap_err();
+        assert!(matches!(error, Error::UnknownSymbol(name) if name == "not_a_symbol"));
+    }
+
+    #[test]
+    fn filters_known_symbols() {
+        let known = known_symbols(FONT, ["home", "not_a_symbol", "settings"]).unwrap();
+        assert_eq!(known, ["home", "settings"]);
+    }
+}
@@ -0,0 +1,62 @@
+#![warn(missing_docs)]
+
+//! # Dioxus Material Symbols Subset
+//!
+//! Reduces a self-hosted Material Symbols font to the icons an app actually uses.
+//!
+//! The outlines of all other symbols are removed while glyph ids, ligatures and metrics are kept,
+//! so the subset is a drop-in replacement for the original font.
+//! Ligatures of removed symbols render as blank space instead of the symbol.
+//! Only fonts with TrueType outlines (`.ttf`) are supported, static and variable ones alike.
+//!
+//! It can be used as a binary (`material-symbols-subset`) or from a build script:
+//!
+//! ```no_run
+//! let font = std::fs::read("assets/MaterialSymbolsOutlined.ttf").unwrap();
+//! let names = dioxus_material_symbols_subset::scan_dir("src").unwrap();
+//! let names = dioxus_material_symbols_subset::known_symbols(&font, names).unwrap();
+//! let subset = dioxus_material_symbols_subset::subset(&font, &names).unwrap();
+//! std::fs::write("assets/MaterialSymbolsOutlined-subset.ttf", subset).unwrap();
+//! ```
+
+use std::fmt;
+
+mod font;
+mod scan;
+
+pub use font::{known_symbols, subset};
+pub use scan::{scan_dir, scan_source};
+
+/// Errors that can occur while subsetting a font
+#[derive(Debug)]
+pub enum Error {
+    /// The font could not be parsed
+    Parse(ttf_parser::FaceParsingError),
+    /// The font has no TrueType outlines (e.g. an `.otf` file with CFF outlines)
+    UnsupportedOutlines,
+    /// A required table is missing or malformed
+    MalformedTable(&'static str),
+    /// A symbol name was not found in the ligatures of the font
+    UnknownSymbol(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(e) => write!(f, "failed to parse font: {e}"),
+            Error::UnsupportedOutlines => {
+                f.write_str("only fonts with TrueType outlines are supported")
+            }
+            Error::MalformedTable(tag) => write!(f, "missing or malformed `{tag}` table"),
+            Error::UnknownSymbol(name) => write!(f, "unknown symbol `{name}`"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ttf_parser::FaceParsingError> for Error {
+    fn from(value: ttf_parser::FaceParsingError) -> Self {
+        Self::Parse(value)
+    }
+}
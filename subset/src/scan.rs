@@ -0,0 +1,99 @@
+//! Finds the symbol names used in Rust source code
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Collects the symbol names used in all `.rs` files in a directory, recursively
+///
+/// Hidden directories and `target` directories are skipped.
+/// See [`scan_source`] for the recognized patterns.
+pub fn scan_dir(dir: impl AsRef<Path>) -> io::Result<BTreeSet<String>> {
+    let mut names = BTreeSet::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+        if path.is_dir() {
+            if !file_name.starts_with('.') && file_name != "target" {
+                names.append(&mut scan_dir(&path)?);
+            }
+        } else if path.extension().is_some_and(|e| e == "rs") {
+            names.append(&mut scan_source(&fs::read_to_string(&path)?));
+        }
+    }
+    Ok(names)
+}
+
+/// Collects the symbol names used in Rust source code
+///
+/// Recognizes `name: "..."` props, `icon!("...")` macro calls and `symbols::...` constants.
+/// The result may contain names which are no symbols, e.g. from `name` props of other components.
+/// Use [`known_symbols`](crate::known_symbols) to filter them.
+pub fn scan_source(source: &str) -> BTreeSet<String> {
+    let mut names = BTreeSet::new();
+    for pattern in ["name:", "icon!("] {
+        for (i, _) in source.match_indices(pattern) {
+            let rest = source[i + pattern.len()..].trim_start();
+            if let Some(literal) = rest.strip_prefix('"') {
+                let name = &literal[..literal.find('"').unwrap_or(0)];
+                if is_symbol_name(name) {
+                    names.insert(name.to_string());
+                }
+            }
+        }
+    }
+    for (i, pattern) in source.match_indices("symbols::") {
+        let rest = &source[i + pattern.len()..];
+        let end = rest
+            .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+            .unwrap_or(rest.len());
+        let constant = &rest[..end];
+        if !constant.is_empty() && !constant.contains(|c: char| c.is_ascii_lowercase()) {
+            let name = constant.strip_prefix('_').unwrap_or(constant);
+            names.insert(name.to_lowercase());
+        }
+    }
+    names
+}
+
+fn is_symbol_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_symbol_names() {
+        let source = r#"
+            rsx!(
+                MaterialIcon { name: "home" }
+                MaterialIcon { name:"arrow_back", size: 24 }
+                MaterialIcon { name: icon!("settings") }
+                MaterialIcon { name: symbols::_3D_ROTATION }
+                MaterialIcon { name: dioxus_material_symbols::symbols::SEARCH }
+            )
+        "#;
+        let names: Vec<String> = scan_source(source).into_iter().collect();
+        assert_eq!(
+            names,
+            ["3d_rotation", "arrow_back", "home", "search", "settings"]
+        );
+    }
+
+    #[test]
+    fn ignores_other_code() {
+        let source = r#"
+            let name: String = format!("{}", 1);
+            Input { name: "First Name" }
+            use dioxus_material_symbols::symbols::{self, Symbol};
+            let symbol = symbols::codepoint;
+        "#;
+        assert!(scan_source(source).is_empty());
+    }
+}
@@ -0,0 +1,508 @@
+#![allow(non_snake_case)]
+
+//! Snapshot tests of the server-side rendered HTML
+//!
+//! The HTML is rendered from the templates of the virtual DOM by a minimal renderer.
+//! Unlike `dioxus_ssr::pre_render`, it doesn't add the hydration markers, so the snapshots check
+//! the elements and attributes of the components, not that the markup hydrates.
+
+use std::fmt::Write;
+use std::io;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use dioxus::dioxus_core::{AttributeValue, DynamicNode, TemplateAttribute, TemplateNode, VNode};
+use dioxus::prelude::*;
+use dioxus_material_symbols::{
+    stylesheet_css, stylesheet_link, ColorRole, CssColor, FontDisplay, MaterialIcon,
+    MaterialIconAnimation, MaterialIconBadge, MaterialIconButton, MaterialIconButtonKind,
+    MaterialIconLayer, MaterialIconMode, MaterialIconSize, MaterialIconStack, MaterialIconStyle,
+    MaterialIconStylesheet, MaterialIconTheme, MaterialIconVariant, SelfHostedFont,
+};
+
+/// Renders a component to HTML
+fn render(app: fn() -> Element) -> String {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let mut html = String::new();
+    render_vnode(&dom, dom.base_scope().root_node(), &mut html);
+    html
+}
+
+/// Renders a component to HTML and returns the warnings logged while rendering
+fn render_with_warnings(app: fn() -> Element) -> (String, String) {
+    #[derive(Clone, Default)]
+    struct Log(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for Log {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let log = Log::default();
+    let writer = log.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::WARN)
+        .with_ansi(false)
+        .without_time()
+        .with_writer(move || writer.clone())
+        .finish();
+    let html = tracing::subscriber::with_default(subscriber, || render(app));
+    let warnings = String::from_utf8(log.0.lock().unwrap().clone()).unwrap();
+    (html, warnings)
+}
+
+fn render_vnode(dom: &VirtualDom, vnode: &VNode, html: &mut String) {
+    for root in vnode.template.roots {
+        render_template_node(dom, vnode, root, html);
+    }
+}
+
+fn render_template_node(dom: &VirtualDom, vnode: &VNode, node: &TemplateNode, html: &mut String) {
+    match node {
+        TemplateNode::Element {
+            tag,
+            attrs,
+            children,
+            ..
+        } => {
+            write!(html, "<{tag}").unwrap();
+            for attr in attrs.iter() {
+                match attr {
+                    TemplateAttribute::Static { name, value, .. } => {
+                        write!(html, " {name}=\"{}\"", escape(value)).unwrap();
+                    }
+                    TemplateAttribute::Dynamic { id } => {
+                        for attr in vnode.dynamic_attrs[*id].iter() {
+                            match &attr.value {
+                                AttributeValue::Text(value) => {
+                                    write!(html, " {}=\"{}\"", attr.name, escape(value)).unwrap()
+                                }
+                                // Like `dioxus-ssr`, boolean attributes are omitted when false
+                                AttributeValue::Bool(true) => {
+                                    write!(html, " {}=true", attr.name).unwrap()
+                                }
+                                AttributeValue::Int(value) => {
+                                    write!(html, " {}={value}", attr.name).unwrap()
+                                }
+                                AttributeValue::Float(value) => {
+                                    write!(html, " {}={value}", attr.name).unwrap()
+                                }
+                                _ => {}
+                            }
+                        }
+                    }
+                }
+            }
+            html.push('>');
+            if *tag == "link" {
+                return;
+            }
+            for child in children.iter() {
+                render_template_node(dom, vnode, child, html);
+            }
+            write!(html, "</{tag}>").unwrap();
+        }
+        TemplateNode::Text { text } => html.push_str(&escape(text)),
+        TemplateNode::Dynamic { id } => match &vnode.dynamic_nodes[*id] {
+            DynamicNode::Text(text) => html.push_str(&escape(&text.value)),
+            DynamicNode::Fragment(nodes) => {
+                for node in nodes {
+                    render_vnode(dom, node, html);
+                }
+            }
+            DynamicNode::Component(component) => {
+                let scope = component
+                    .mounted_scope(*id, vnode, dom)
+                    .expect("component is not mounted");
+                render_vnode(dom, scope.root_node(), html);
+            }
+            DynamicNode::Placeholder(_) => {}
+        },
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[test]
+fn decorative_icon() {
+    fn App() -> Element {
+        rsx!(MaterialIcon { name: "home" })
+    }
+    assert_eq!(
+        render(App),
+        r#"<span class="material-symbols material-symbols-outlined material-symbols-rounded material-symbols-sharp" style="font-size: inherit;   user-select: none;" aria-hidden="true">home</span>"#
+    );
+}
+
+#[test]
+fn labelled_icon() {
+    fn App() -> Element {
+        rsx!(MaterialIcon {
+            name: "warning",
+            size: 20,
+            color: ColorRole::Error,
+            fill: true,
+            variant: MaterialIconStyle::Rounded,
+            label: "Warning",
+            title: "Could not save",
+        })
+    }
+    assert_eq!(
+        render(App),
+        r#"<span class="material-symbols material-symbols-rounded" style="font-size: 20px; color: var(--md-sys-color-error, #b3261e); font-variation-settings: 'FILL' 1; user-select: none;" role="img" aria-label="Warning" title="Could not save">warning</span>"#
+    );
+}
+
+#[cfg(feature = "macros")]
+#[test]
+fn checked_icon_name() {
+    fn App() -> Element {
+        rsx!(
+            MaterialIcon { name: dioxus_material_symbols::icon!("home") }
+            MaterialIcon { name: dioxus_material_symbols::symbols::HOME }
+        )
+    }
+    let decorative = r#"<span class="material-symbols material-symbols-outlined material-symbols-rounded material-symbols-sharp" style="font-size: inherit;   user-select: none;" aria-hidden="true">home</span>"#;
+    assert_eq!(render(App), decorative.repeat(2));
+}
+
+#[test]
+fn codepoint_icon() {
+    fn App() -> Element {
+        rsx!(
+            MaterialIcon { name: "home", mode: MaterialIconMode::Codepoint }
+            MaterialIcon { name: "not_a_symbol", mode: MaterialIconMode::Codepoint }
+        )
+    }
+    let html = render(App);
+    assert!(html.contains(">\u{e88a}</span>"));
+    assert!(html.contains(">not_a_symbol</span>"));
+}
+
+#[test]
+fn hidden_until_loaded() {
+    fn App() -> Element {
+        rsx!(MaterialIcon {
+            name: "home",
+            hide_until_loaded: true
+        })
+    }
+    assert!(render(App).contains("width: 1em; overflow: hidden; visibility: hidden;"));
+}
+
+#[test]
+fn mirrored_icons() {
+    fn App() -> Element {
+        rsx!(
+            div { dir: "rtl",
+                MaterialIcon { name: "arrow_forward" }
+                MaterialIcon { name: "home" }
+                MaterialIcon { name: "home", mirror: true }
+                MaterialIcon { name: "send", mirror: false }
+            }
+        )
+    }
+    let html = render(App);
+    let classes: Vec<&str> = html
+        .split(r#"class=""#)
+        .skip(1)
+        .map(|class| class.split('"').next().unwrap())
+        .collect();
+    assert!(classes[0].ends_with(" material-symbols-directional"));
+    assert!(!classes[1].contains("mirrored") && !classes[1].contains("directional"));
+    assert!(classes[2].ends_with(" material-symbols-mirrored"));
+    assert!(!classes[3].contains("mirrored") && !classes[3].contains("directional"));
+}
+
+#[test]
+fn badges() {
+    fn App() -> Element {
+        rsx!(
+            MaterialIcon { name: "mail", badge: MaterialIconBadge::count(1500) }
+            MaterialIcon { name: "chat", badge: MaterialIconBadge::count(12).max(9).label("12 unread chats") }
+            MaterialIcon { name: "notifications", badge: MaterialIconBadge::dot() }
+            MaterialIcon { name: "inbox", badge: MaterialIconBadge::count(0) }
+        )
+    }
+    let html = render(App);
+    let badges: Vec<&str> = html
+        .split(r#"<span class="material-symbols-badged">"#)
+        .collect();
+    assert_eq!(badges.len(), 4);
+    assert!(badges[1].contains(r#"<span class="material-symbols-badge"><span aria-hidden="true">999+</span><span class="material-symbols-visually-hidden">1500</span></span>"#));
+    assert!(badges[2].contains(r#"<span aria-hidden="true">9+</span><span class="material-symbols-visually-hidden">12 unread chats</span>"#));
+    assert!(badges[3].contains(
+        r#"<span class="material-symbols-badge material-symbols-badge-dot"></span></span>"#
+    ));
+    assert!(html.ends_with(">inbox</span>"));
+}
+
+#[test]
+fn icon_stack() {
+    fn App() -> Element {
+        rsx!(
+            MaterialIconTheme { size: 20, color: "red",
+                MaterialIconStack { label: "Syncing",
+                    MaterialIconLayer { name: "cloud" }
+                    MaterialIconLayer { name: "sync", scale: 0.5, offset_x: 0.25, offset_y: 0.25, color: "white", fill: true }
+                }
+            }
+        )
+    }
+    assert_eq!(
+        render(App),
+        concat!(
+            r#"<span class="material-symbols-stack" style="font-size: 20px;" role="img" aria-label="Syncing">"#,
+            r#"<span class="material-symbols-stack-layer" style="font-size: 1em; transform: translate(0%, 0%);"><span class="material-symbols material-symbols-outlined material-symbols-rounded material-symbols-sharp" style="font-size: inherit; color: #ff0000;  user-select: none;" aria-hidden="true">cloud</span></span>"#,
+            r#"<span class="material-symbols-stack-layer" style="font-size: 0.5em; transform: translate(25%, 25%);"><span class="material-symbols material-symbols-outlined material-symbols-rounded material-symbols-sharp" style="font-size: inherit; color: #ffffff; font-variation-settings: 'FILL' 1; user-select: none;" aria-hidden="true">sync</span></span>"#,
+            r#"</span>"#,
+        )
+    );
+}
+
+#[test]
+fn animated_icons() {
+    fn App() -> Element {
+        rsx!(
+            MaterialIcon { name: "progress_activity", animation: MaterialIconAnimation::spin() }
+            MaterialIcon {
+                name: "notifications",
+                animation: MaterialIconAnimation::shake().duration(Duration::from_millis(300)).iterations(2),
+            }
+        )
+    }
+    let html = render(App);
+    assert!(html.contains(r#"material-symbols-sharp material-symbols-animated material-symbols-spin" style="font-size: inherit;   user-select: none; animation-duration: 1000ms; animation-iteration-count: infinite;""#));
+    assert!(html.contains(r#"material-symbols-sharp material-symbols-animated material-symbols-shake" style="font-size: inherit;   user-select: none; animation-duration: 300ms; animation-iteration-count: 2;""#));
+
+    let css = stylesheet_css(&[MaterialIconVariant::Rounded], None);
+    assert_eq!(css.matches("@keyframes material-symbols-spin").count(), 1);
+    assert!(css.contains("@media (prefers-reduced-motion: reduce)"));
+}
+
+#[test]
+fn sized_icons() {
+    fn App() -> Element {
+        rsx!(
+            MaterialIcon { name: "home", size: 32 }
+            MaterialIcon { name: "home", size: MaterialIconSize::DP_40 }
+            MaterialIcon { name: "home", size: MaterialIconSize::Em(1.5) }
+            MaterialIcon { name: "home", size: "calc(1rem + 4px)" }
+            MaterialIcon { name: "home", size: "1em; color: red" }
+        )
+    }
+    let html = render(App);
+    let sizes: Vec<&str> = html
+        .split("font-size: ")
+        .skip(1)
+        .map(|style| style.split(';').next().unwrap())
+        .collect();
+    assert_eq!(
+        sizes,
+        ["32px", "40px", "1.5em", "calc(1rem + 4px)", "inherit"]
+    );
+    assert!(!html.contains("color: red"));
+}
+
+#[test]
+fn sanitized_colors() {
+    fn App() -> Element {
+        rsx!(
+            MaterialIcon { name: "home", color: "rgb(0 0 255 / 50%)" }
+            MaterialIcon { name: "home", color: "red; background: url(https://example.com)" }
+            MaterialIcon { name: "home", color: CssColor::rgb(103, 80, 164).lighten(0.2).opacity(0.5) }
+        )
+    }
+    let html = render(App);
+    let colors: Vec<&str> = html
+        .split("color: ")
+        .skip(1)
+        .map(|style| style.split(';').next().unwrap())
+        .collect();
+    assert_eq!(
+        colors,
+        [
+            "rgba(0, 0, 255, 0.5)",
+            "currentColor",
+            "rgba(160, 145, 201, 0.5)"
+        ]
+    );
+    assert!(!html.contains("background"));
+}
+
+#[cfg(feature = "svg-outlined")]
+#[test]
+fn svg_icons() {
+    fn App() -> Element {
+        rsx!(
+            MaterialIcon { name: "home", mode: MaterialIconMode::SvgOutlined, size: 24, label: "Home" }
+            MaterialIcon { name: "not_a_symbol", mode: MaterialIconMode::SvgOutlined }
+        )
+    }
+    let html = render(App);
+    let (known, unknown) = html.split_once("</span>").unwrap();
+    assert!(known.starts_with(
+        r#"<span class="material-symbols-svg" style="display: inline-flex; vertical-align: middle; font-size: 24px; " role="img" aria-label="Home"><svg width="1em" height="1em" viewBox="0 0 24 24" fill="currentColor"><path d="M"#
+    ));
+    assert_eq!(
+        unknown,
+        r#"<span class="material-symbols-svg" style="display: inline-flex; vertical-align: middle; " aria-hidden="true"><svg width="1em" height="1em" viewBox="0 0 24 24" fill="currentColor"></svg></span>"#
+    );
+}
+
+#[test]
+fn themed_icon() {
+    fn App() -> Element {
+        rsx!(
+            MaterialIconTheme { size: 24, color: "red", weight: 300,
+                MaterialIconTheme { size: 20,
+                    MaterialIcon { name: "settings", class: "toolbar", id: "settings" }
+                }
+            }
+        )
+    }
+    assert_eq!(
+        render(App),
+        r#"<span class="material-symbols material-symbols-outlined material-symbols-rounded material-symbols-sharp toolbar" style="font-size: 20px; color: #ff0000; font-variation-settings: 'wght' 300; user-select: none;" aria-hidden="true" id="settings">settings</span>"#
+    );
+}
+
+#[test]
+fn icon_button() {
+    fn App() -> Element {
+        rsx!(MaterialIconButton {
+            name: "favorite",
+            label: "Favorite",
+            kind: MaterialIconButtonKind::Filled,
+            selected: true,
+        })
+    }
+    assert_eq!(
+        render(App),
+        r#"<button type="button" class="material-symbols-button material-symbols-button-filled" aria-label="Favorite" aria-pressed="true"><span class="material-symbols material-symbols-outlined material-symbols-rounded material-symbols-sharp" style="font-size: 24px; color: currentColor; font-variation-settings: 'FILL' 1; user-select: none;" aria-hidden="true">favorite</span></button>"#
+    );
+}
+
+#[test]
+fn google_fonts_stylesheet() {
+    fn App() -> Element {
+        rsx!(MaterialIconStylesheet {
+            variants: vec![MaterialIconVariant::Outlined, MaterialIconVariant::Rounded],
+        })
+    }
+    let variants = [MaterialIconVariant::Outlined, MaterialIconVariant::Rounded];
+    assert!(stylesheet_link(&variants, Some(FontDisplay::Swap))
+        .unwrap()
+        .ends_with(r#"-50..200&amp;display=swap" rel="stylesheet">"#));
+    let link = stylesheet_link(&variants, None).unwrap();
+    assert_eq!(
+        link,
+        r#"<link href="https://fonts.googleapis.com/css2?family=Material+Symbols+Outlined:opsz,wght,FILL,GRAD@20..48,100..700,0..1,-50..200&amp;family=Material+Symbols+Rounded:opsz,wght,FILL,GRAD@20..48,100..700,0..1,-50..200" rel="stylesheet">"#
+    );
+    assert_eq!(
+        render(App),
+        format!(
+            "{link}<style>{}</style>",
+            escape(&stylesheet_css(&variants, None))
+        )
+    );
+}
+
+#[test]
+fn self_hosted_stylesheet() {
+    fn App() -> Element {
+        rsx!(MaterialIconStylesheet {
+            variant: MaterialIconVariant::SelfHosted(SelfHostedFont {
+                sources: vec!["/fonts/MaterialSymbolsOutlined.woff2".into()],
+                ..Default::default()
+            }),
+        })
+    }
+    let variant = MaterialIconVariant::SelfHosted(SelfHostedFont {
+        sources: vec!["/fonts/MaterialSymbolsOutlined.woff2".into()],
+        ..Default::default()
+    });
+    let css = stylesheet_css(std::slice::from_ref(&variant), None);
+    assert!(css.contains("src: url(\"/fonts/MaterialSymbolsOutlined.woff2\") format('woff2');"));
+    assert_eq!(stylesheet_link(&[variant], None), None);
+    assert_eq!(render(App), format!("<style>{}</style>", escape(&css)));
+}
+
+#[test]
+fn escaped_font_urls() {
+    let variant = MaterialIconVariant::SelfHosted(SelfHostedFont {
+        sources: vec![r#"/fonts/a"b\c.ttf"#.into(), "/fonts/icons".into()],
+        ..Default::default()
+    });
+    let css = stylesheet_css(&[variant], None);
+    assert!(
+        css.contains(r#"src: url("/fonts/a\"b\\c.ttf") format('truetype'), url("/fonts/icons");"#)
+    );
+}
+
+#[test]
+fn clickable_icon() {
+    fn Labelled() -> Element {
+        rsx!(MaterialIcon {
+            name: "home",
+            label: "Home",
+            onclick: |_| {}
+        })
+    }
+    let (html, warnings) = render_with_warnings(Labelled);
+    assert_eq!(
+        html,
+        r#"<span class="material-symbols material-symbols-outlined material-symbols-rounded material-symbols-sharp" style="font-size: inherit;   user-select: none;" role="img" aria-label="Home">home</span>"#
+    );
+    assert_eq!(warnings, "");
+
+    fn Unlabelled() -> Element {
+        rsx!(MaterialIcon {
+            name: "home",
+            onclick: |_| {}
+        })
+    }
+    // Without a label, the name is read out as text, which the warning points out
+    let (html, warnings) = render_with_warnings(Unlabelled);
+    if cfg!(debug_assertions) {
+        assert!(warnings.contains("Interactive MaterialIcon `home` has no label"));
+        assert!(warnings.contains("MaterialIconButton"));
+    }
+    assert_eq!(
+        html,
+        r#"<span class="material-symbols material-symbols-outlined material-symbols-rounded material-symbols-sharp" style="font-size: inherit;   user-select: none;">home</span>"#
+    );
+}
+
+#[cfg(feature = "metadata")]
+#[test]
+fn picker_renders_visible_rows() {
+    fn App() -> Element {
+        rsx!(dioxus_material_symbols::MaterialIconPicker {
+            columns: 4,
+            rows: 3,
+            onselect: |_| {},
+        })
+    }
+    let html = render(App);
+    // The visible rows and the rows rendered below them
+    assert_eq!(html.matches(r#"role="option""#).count(), 4 * (3 + 2));
+    assert_eq!(
+        html.matches(r#"aria-activedescendant="material-symbols-picker-0-0""#)
+            .count(),
+        2
+    );
+    assert!(html.contains(r#"role="combobox""#));
+    assert!(html.contains(r#"aria-expanded="true""#));
+}